@@ -0,0 +1,30 @@
+// Benchmark sin dependencias: `cargo bench --bench first_word`
+use std::hint::black_box;
+use std::time::Instant;
+
+use rust_teo::words;
+
+fn main() {
+    // Una sola palabra larga: el peor caso, hay que recorrerla entera
+    let text = "a".repeat(10_000_000);
+    let runs = 20;
+
+    let start = Instant::now();
+    let mut scalar = "";
+    for _ in 0..runs {
+        scalar = words::first_word(black_box(&text));
+    }
+    let scalar_time = start.elapsed() / runs;
+
+    let start = Instant::now();
+    let mut fast = "";
+    for _ in 0..runs {
+        fast = words::first_word_fast(black_box(&text));
+    }
+    let fast_time = start.elapsed() / runs;
+
+    assert_eq!(scalar, fast);
+    println!("{} bytes", text.len());
+    println!("first_word:      {:?}", scalar_time);
+    println!("first_word_fast: {:?}", fast_time);
+}
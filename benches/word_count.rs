@@ -0,0 +1,29 @@
+// Benchmark sin dependencias: `cargo bench --bench word_count`
+use std::hint::black_box;
+use std::time::Instant;
+
+use rust_teo::words;
+
+fn main() {
+    let text = "lorem ipsum dolor sit amet, consectetur adipiscing elit\n".repeat(200_000);
+    let runs = 20;
+
+    let start = Instant::now();
+    let mut scalar = 0;
+    for _ in 0..runs {
+        scalar = black_box(&text).split_whitespace().count();
+    }
+    let scalar_time = start.elapsed() / runs;
+
+    let start = Instant::now();
+    let mut fast = 0;
+    for _ in 0..runs {
+        fast = words::word_count_fast(black_box(&text));
+    }
+    let fast_time = start.elapsed() / runs;
+
+    assert_eq!(scalar, fast);
+    println!("{} bytes, {} palabras", text.len(), fast);
+    println!("split_whitespace: {:?}", scalar_time);
+    println!("word_count_fast:  {:?}", fast_time);
+}
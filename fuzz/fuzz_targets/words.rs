@@ -0,0 +1,45 @@
+// Texto UTF-8 arbitrario para las funciones de palabras y slices: `cargo fuzz run words`.
+// Ninguna debe entrar en pánico y todo lo que devuelven debe ser un slice válido de la entrada.
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rust_teo::slice::safe_slice;
+use rust_teo::words::{first_word, nth_word, words};
+
+// `part` apunta dentro de `whole` y empieza y termina en límites de char
+fn is_subslice(whole: &str, part: &str) -> bool {
+    let whole_start = whole.as_ptr() as usize;
+    let start = part.as_ptr() as usize;
+    if start < whole_start || start + part.len() > whole_start + whole.len() {
+        return false;
+    }
+    let offset = start - whole_start;
+    whole.is_char_boundary(offset) && whole.is_char_boundary(offset + part.len())
+}
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(s) = std::str::from_utf8(data) else {
+        return;
+    };
+
+    assert!(is_subslice(s, first_word(s)));
+    for n in 0..4 {
+        if let Some(word) = nth_word(s, n) {
+            assert!(is_subslice(s, word));
+        }
+    }
+    for word in words(s) {
+        assert!(!word.is_empty());
+        assert!(is_subslice(s, word));
+    }
+
+    // Índices sacados de los propios bytes, muchas veces en mitad de un carácter multibyte
+    for pair in data.windows(2).take(16) {
+        let start = pair[0] as usize % (s.len() + 2);
+        let end = pair[1] as usize % (s.len() + 2);
+        if let Some(part) = safe_slice(s, start, end) {
+            assert_eq!(part.len(), end - start);
+            assert!(is_subslice(s, part));
+        }
+    }
+});
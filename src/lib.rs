@@ -0,0 +1,8 @@
+// Sin la feature `std` (activa por defecto) el crate compila con `#![no_std]` y sólo expone
+// las funciones que trabajan con slices.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod slice;
+#[cfg(feature = "std")]
+pub mod text;
+pub mod words;
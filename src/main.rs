@@ -1,6 +1,18 @@
-mod slice;
+use std::io::{self, BufRead, Write};
+
+use rust_teo::words;
 
 fn main() {
+    // Modo CLI: `cat file.txt | rust_teo --first-word` imprime la primera palabra de cada línea.
+    // Sin argumentos se mantiene el recorrido de ejemplos.
+    if std::env::args().skip(1).any(|arg| arg == "--first-word") {
+        if let Err(e) = print_first_words() {
+            eprintln!("error: {}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
     { // s is not valid here, since it's not yet declared
         let s = "hello";   // s is valid from this point forward
         println!("{}", s);
@@ -122,11 +134,21 @@ fn main() {
     println!("reference_to_nothing = {}", reference_to_nothing);
 
     /* Slice */
-    slice::slice();
+    rust_teo::slice::slice();
 } // Here, x goes out of scope, then s. However, because s's value was moved,
 // nothing special happens. || Here, s3 goes out of scope and is dropped. s2 was moved, so nothing
 // happens. s1 goes out of scope and is dropped.
 
+// Una línea sólo con espacios imprime una línea vacía
+fn print_first_words() -> io::Result<()> {
+    let mut out = io::stdout().lock();
+    for line in io::stdin().lock().lines() {
+        let line = line?;
+        writeln!(out, "{}", words::first_word_opt(&line).unwrap_or(""))?;
+    }
+    Ok(())
+}
+
 fn takes_ownership(some_string: String) { // some_string comes into scope
     println!("{some_string}");
 } // Here, some_string goes out of scope and `drop` is called. The backing
@@ -1,3 +1,10 @@
+use core::error::Error;
+use core::fmt;
+
+#[cfg(feature = "std")]
+use crate::words;
+
+#[cfg(feature = "std")]
 pub fn slice() {
     // Slice o rebanada
     // Las porciones permiten referenciar una secuencia contigua de elementos en una colección . Una porción es un tipo de referencia, por lo que no tiene propiedad.
@@ -53,24 +60,24 @@ pub fn slice() {
     let my_string = String::from("hello world");
 
     // `first_word` works on slices of `String`s, whether partial or whole.
-    let word = first_world_with_slice_str(&my_string[0..6]);
+    let word = words::first_word(&my_string[0..6]);
     println!("{}", word);
-    let word = first_world_with_slice_str(&my_string[..]);
+    let word = words::first_word(&my_string[..]);
     println!("{}", word);
     // `first_word` also works on references to `String`s, which are equivalent
     // to whole slices of `String`s.
-    let word = first_world_with_slice_str(&my_string);
+    let word = words::first_word(&my_string);
     println!("{}", word);
     let  my_string_literal = "hello world";
     // `first_word` works on slices of string literals, whether partial or
     // whole.
-    let word = first_world_with_slice_str(&my_string_literal[0..5]);
+    let word = words::first_word(&my_string_literal[0..5]);
     println!("{}", word);
-    let word = first_world_with_slice_str(&my_string_literal[..]);
+    let word = words::first_word(&my_string_literal[..]);
     println!("{}", word);
     // Because string literals *are* string slices already,
     // this works too, without the slice syntax!
-    let word = first_world_with_slice_str(my_string_literal);
+    let word = words::first_word(my_string_literal);
     println!("{}", word);
 
     // Otras rebanadas/slice
@@ -81,6 +88,7 @@ pub fn slice() {
     // Los conceptos de propiedad, préstamo y porciones garantizan la seguridad de la memoria en los programas Rust durante la compilación. El lenguaje Rust te permite controlar el uso de la memoria, al igual que otros lenguajes de programación de sistemas. Sin embargo, al permitir que el propietario de los datos los limpie automáticamente cuando este deja de estar dentro del alcance, no es necesario escribir ni depurar código adicional para obtener este control.
 }
 
+#[cfg(feature = "std")]
 fn first_word(s: &String) -> usize {
     let bytes = s.as_bytes();
 
@@ -93,6 +101,7 @@ fn first_word(s: &String) -> usize {
     s.len()
 }
 
+#[cfg(feature = "std")]
 fn first_world_with_slice(s: &String) -> &str {
 // Si tenemos una porción de cadena, podemos pasarla directamente. Si tenemos un String, podemos pasar una porción de Stringo una referencia a String. Esta flexibilidad aprovecha las coerciones de desreferencia
 // fn first_world_with_slice(s: &str) -> &str {
@@ -106,13 +115,128 @@ fn first_world_with_slice(s: &String) -> &str {
     &s[..]
 }
 
-fn first_world_with_slice_str(s: &str) -> &str {
-    let bytes = s.as_bytes();
-    for (i, &item) in bytes.iter().enumerate() {
-        if item == b' ' {
-            return &s[0..i];
+// Como `&s[start..end]`, pero devuelve `None` en lugar de entrar en pánico si algún índice está fuera
+// del texto o cae en medio de un carácter multibyte
+pub fn safe_slice(s: &str, start: usize, end: usize) -> Option<&str> {
+    s.get(start..end)
+}
+
+pub fn first_element<T>(slice: &[T]) -> Option<&T> {
+    slice.first()
+}
+
+// Los primeros `n` elementos; si `n` supera la longitud devuelve el slice completo en lugar de entrar en pánico
+pub fn first_n<T>(slice: &[T], n: usize) -> &[T] {
+    &slice[..n.min(slice.len())]
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SliceError {
+    StartOutOfBounds { start: usize, len: usize },
+    EndOutOfBounds { end: usize, len: usize },
+    StartAfterEnd { start: usize, end: usize },
+    NotCharBoundary(usize),
+}
+
+impl fmt::Display for SliceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SliceError::StartOutOfBounds { start, len } => {
+                write!(f, "start index {} is out of bounds for a string of {} bytes", start, len)
+            }
+            SliceError::EndOutOfBounds { end, len } => {
+                write!(f, "end index {} is out of bounds for a string of {} bytes", end, len)
+            }
+            SliceError::StartAfterEnd { start, end } => {
+                write!(f, "start index {} is after end index {}", start, end)
+            }
+            SliceError::NotCharBoundary(index) => {
+                write!(f, "byte index {} is not on a char boundary", index)
+            }
         }
     }
+}
 
-    &s[..]
-}
\ No newline at end of file
+impl Error for SliceError {}
+
+// Igual que `safe_slice`, pero indicando el motivo del fallo
+pub fn try_slice(s: &str, start: usize, end: usize) -> Result<&str, SliceError> {
+    let len = s.len();
+    if start > len {
+        return Err(SliceError::StartOutOfBounds { start, len });
+    }
+    if end > len {
+        return Err(SliceError::EndOutOfBounds { end, len });
+    }
+    if start > end {
+        return Err(SliceError::StartAfterEnd { start, end });
+    }
+    for index in [start, end] {
+        if !s.is_char_boundary(index) {
+            return Err(SliceError::NotCharBoundary(index));
+        }
+    }
+    Ok(&s[start..end])
+}
+
+// El slice sin su primer y último elemento; vacío si tiene 2 elementos o menos
+pub fn middle<T>(slice: &[T]) -> &[T] {
+    match slice {
+        [_, inner @ .., _] => inner,
+        _ => &[],
+    }
+}
+
+// Trozos de `size` elementos (el último puede ser más corto). Un `size` de 0 se trata como 1.
+pub fn chunk<T>(slice: &[T], size: usize) -> impl Iterator<Item = &[T]> {
+    slice.chunks(size.max(1))
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    // `&s[0..2]` en "héllo" cortaría la `é` por la mitad
+    #[test]
+    fn safe_slice_none_instead_of_panic() {
+        let s = "héllo";
+        assert_eq!(safe_slice(s, 0, 2), None);
+        assert_eq!(safe_slice(s, 0, 3), Some("hé"));
+        assert_eq!(safe_slice(s, 3, 99), None);
+        assert_eq!(safe_slice(s, 4, 2), None);
+    }
+
+    #[test]
+    fn try_slice_reports_reason() {
+        let s = "héllo";
+        assert_eq!(try_slice(s, 0, 3), Ok("hé"));
+        assert_eq!(try_slice(s, 9, 9), Err(SliceError::StartOutOfBounds { start: 9, len: 6 }));
+        assert_eq!(try_slice(s, 0, 9), Err(SliceError::EndOutOfBounds { end: 9, len: 6 }));
+        assert_eq!(try_slice(s, 3, 1), Err(SliceError::StartAfterEnd { start: 3, end: 1 }));
+        assert_eq!(try_slice(s, 0, 2), Err(SliceError::NotCharBoundary(2)));
+        assert_eq!(SliceError::NotCharBoundary(2).to_string(), "byte index 2 is not on a char boundary");
+    }
+
+    #[test]
+    fn first_element_and_first_n() {
+        let a = [1, 2, 3, 4, 5];
+        assert_eq!(first_element(&a), Some(&1));
+        assert_eq!(first_element::<i32>(&[]), None);
+        assert_eq!(first_n(&a, 2), &[1, 2]);
+        assert_eq!(first_n(&a, 10), &a); // n mayor que la longitud se recorta
+        let names = ["ana", "luis"];
+        assert_eq!(first_element(&names), Some(&"ana"));
+        assert_eq!(first_n(&names, 5), &names);
+    }
+
+    #[test]
+    fn middle_and_chunk_borrow() {
+        let a = [1, 2, 3, 4, 5];
+        assert_eq!(middle(&a), &[2, 3, 4]);
+        assert_eq!(middle(&[1, 2]), &[] as &[i32]);
+        assert_eq!(middle::<i32>(&[]), &[] as &[i32]);
+        let pieces: Vec<&[i32]> = chunk(&a, 2).collect();
+        assert_eq!(pieces, vec![&[1, 2][..], &[3, 4], &[5]]);
+        assert_eq!(chunk::<i32>(&[], 3).count(), 0);
+    }
+}
@@ -0,0 +1,1428 @@
+use std::borrow::Cow;
+use std::cell::Cell;
+use std::collections::HashSet;
+
+#[cfg(feature = "unicode")]
+use unicode_segmentation::UnicodeSegmentation;
+
+pub fn map_lines(s: &str, f: impl Fn(&str) -> String) -> String {
+    if s.is_empty() {
+        return String::new();
+    }
+    // Si existe un salto de línea final lo quitamos y lo volvemos a añadir al final
+    let (body, trailing_newline) = match s.strip_suffix('\n') {
+        Some(body) => (body, true),
+        None => (s, false),
+    };
+
+    let mut result = body.split('\n').map(f).collect::<Vec<String>>().join("\n");
+    if trailing_newline {
+        result.push('\n');
+    }
+    result
+}
+
+pub fn number_lines(s: &str, start: usize) -> String {
+    let n = Cell::new(start);
+    map_lines(s, |line| {
+        let numbered = format!("{}\t{}", n.get(), line);
+        n.set(n.get() + 1);
+        numbered
+    })
+}
+
+// A diferencia de str::find, que devuelve un offset en bytes, devuelve la posición en chars
+pub fn char_index_of(s: &str, c: char) -> Option<usize> {
+    s.chars().position(|item| item == c)
+}
+
+pub fn pad_words(s: &str, width: usize) -> String {
+    s.split_whitespace()
+        .map(|word| format!("{:<width$}", word, width = width))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+#[cfg(feature = "unicode")]
+pub fn graphemes(s: &str) -> Vec<&str> {
+    s.graphemes(true).collect()
+}
+
+pub fn trim_prefix<'a>(s: &'a str, prefix: &str) -> &'a str {
+    s.strip_prefix(prefix).unwrap_or(s)
+}
+
+// Heurística para palabras en inglés: cuenta grupos de vocales y descuenta la `e` muda final
+pub fn count_syllables(word: &str) -> usize {
+    let word = word.to_lowercase();
+    let is_vowel = |c: char| matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'y');
+
+    let mut count = 0;
+    let mut previous_vowel = false;
+    for c in word.chars() {
+        let vowel = is_vowel(c);
+        if vowel && !previous_vowel {
+            count += 1;
+        }
+        previous_vowel = vowel;
+    }
+
+    // La `e` final es muda salvo en terminaciones como "-le" (apple, table)
+    if count > 1 && word.ends_with('e') && !word.ends_with("le") {
+        count -= 1;
+    }
+
+    count.max(1)
+}
+
+// Sólo cuentan como palabras los tokens con letras; números y signos sueltos no suman palabras ni sílabas
+pub fn flesch_reading_ease(s: &str) -> f64 {
+    let words: Vec<&str> = s
+        .split_whitespace()
+        .map(|word| word.trim_matches(|c: char| !c.is_alphabetic()))
+        .filter(|word| !word.is_empty())
+        .collect();
+    if words.is_empty() {
+        return 0.0;
+    }
+
+    let syllables: usize = words.iter().map(|word| count_syllables(word)).sum();
+    let words = words.len() as f64;
+    let sentences = count_sentences(s) as f64;
+
+    206.835 - 1.015 * (words / sentences) - 84.6 * (syllables as f64 / words)
+}
+
+// Cuenta grupos de `.`, `!` o `?`; un texto sin terminador cuenta como una oración
+fn count_sentences(s: &str) -> usize {
+    let mut count = 0;
+    let mut pending = false;
+    for c in s.chars() {
+        if matches!(c, '.' | '!' | '?') {
+            if pending {
+                count += 1;
+                pending = false;
+            }
+        } else if !c.is_whitespace() {
+            pending = true;
+        }
+    }
+    if pending {
+        count += 1;
+    }
+    count.max(1)
+}
+
+// Las comillas simples y dobles agrupan espacios y se eliminan; `\` escapa el siguiente char
+pub fn shell_split(s: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    // Distingue un token vacío entre comillas ("") de no tener token
+    let mut in_token = false;
+    let mut quote: Option<char> = None;
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        match (quote, c) {
+            (Some('\''), '\'') => quote = None,
+            (Some('\''), _) => current.push(c),
+            (Some('"'), '"') => quote = None,
+            (_, '\\') => {
+                if let Some(escaped) = chars.next() {
+                    current.push(escaped);
+                }
+                in_token = true;
+            }
+            (Some(_), _) => current.push(c),
+            (None, '\'' | '"') => {
+                quote = Some(c);
+                in_token = true;
+            }
+            (None, _) if c.is_whitespace() => {
+                if in_token {
+                    tokens.push(std::mem::take(&mut current));
+                    in_token = false;
+                }
+            }
+            (None, _) => {
+                current.push(c);
+                in_token = true;
+            }
+        }
+    }
+    if in_token {
+        tokens.push(current);
+    }
+    tokens
+}
+
+pub fn redact_numbers(s: &str, mask: char) -> String {
+    s.chars()
+        .map(|c| if c.is_ascii_digit() { mask } else { c })
+        .collect()
+}
+
+pub fn word_after<'a>(s: &'a str, target: &str) -> Option<&'a str> {
+    let mut words = s.split_whitespace();
+    words.find(|word| *word == target)?;
+    words.next()
+}
+
+pub fn words_between<'a>(s: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let mut words = word_offsets(s);
+    let (start_offset, start_word) = words.find(|(_, word)| *word == start)?;
+    let (end_offset, _) = words.find(|(_, word)| *word == end)?;
+    // Las palabras intermedias son contiguas en `s`, así que basta con recortar los espacios
+    Some(s[start_offset + start_word.len()..end_offset].trim())
+}
+
+// Cada palabra junto a su offset en bytes dentro de `s`
+fn word_offsets(s: &str) -> impl Iterator<Item = (usize, &str)> {
+    s.split_whitespace()
+        .map(move |word| (word.as_ptr() as usize - s.as_ptr() as usize, word))
+}
+
+pub fn fold_accents(s: &str) -> String {
+    s.chars().map(fold_accent).collect()
+}
+
+fn fold_accent(c: char) -> char {
+    match c {
+        'á' | 'à' | 'â' | 'ä' | 'ã' | 'å' => 'a',
+        'Á' | 'À' | 'Â' | 'Ä' | 'Ã' | 'Å' => 'A',
+        'é' | 'è' | 'ê' | 'ë' => 'e',
+        'É' | 'È' | 'Ê' | 'Ë' => 'E',
+        'í' | 'ì' | 'î' | 'ï' => 'i',
+        'Í' | 'Ì' | 'Î' | 'Ï' => 'I',
+        'ó' | 'ò' | 'ô' | 'ö' | 'õ' => 'o',
+        'Ó' | 'Ò' | 'Ô' | 'Ö' | 'Õ' => 'O',
+        'ú' | 'ù' | 'û' | 'ü' => 'u',
+        'Ú' | 'Ù' | 'Û' | 'Ü' => 'U',
+        'ý' | 'ÿ' => 'y',
+        'Ý' => 'Y',
+        'ñ' => 'n',
+        'Ñ' => 'N',
+        'ç' => 'c',
+        'Ç' => 'C',
+        _ => c,
+    }
+}
+
+pub fn contains_ci_accent_insensitive(haystack: &str, needle: &str) -> bool {
+    fold_accents(haystack)
+        .to_lowercase()
+        .contains(&fold_accents(needle).to_lowercase())
+}
+
+pub fn dedup_chars(s: &str) -> String {
+    let mut seen = HashSet::new();
+    s.chars().filter(|c| seen.insert(*c)).collect()
+}
+
+pub fn render_table(rows: &[Vec<&str>]) -> String {
+    let columns = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+    let widths: Vec<usize> = (0..columns)
+        .map(|i| {
+            rows.iter()
+                .filter_map(|row| row.get(i))
+                .map(|cell| cell.chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    rows.iter()
+        .map(|row| {
+            let last = row.len().saturating_sub(1);
+            row.iter()
+                .enumerate()
+                .map(|(i, cell)| {
+                    // La última celda no se rellena para no dejar espacios al final
+                    if i == last {
+                        cell.to_string()
+                    } else {
+                        format!("{:<width$}", cell, width = widths[i])
+                    }
+                })
+                .collect::<Vec<String>>()
+                .join("  ")
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+pub fn first_non_whitespace(s: &str) -> Option<usize> {
+    s.char_indices()
+        .find(|(_, c)| !c.is_whitespace())
+        .map(|(i, _)| i)
+}
+
+pub fn wrap_hyphenate(s: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    let mut current_len = 0;
+
+    for word in s.split_whitespace() {
+        let mut rest: Vec<char> = word.chars().collect();
+
+        // Una palabra más larga que la línea se parte dejando sitio para el guion
+        while rest.len() > width {
+            if !current.is_empty() {
+                lines.push(std::mem::take(&mut current));
+                current_len = 0;
+            }
+            let take = if width > 1 { width - 1 } else { 1 };
+            let mut segment: String = rest.drain(..take).collect();
+            if width > 1 {
+                segment.push('-');
+            }
+            lines.push(segment);
+        }
+        if rest.is_empty() {
+            continue;
+        }
+
+        if current.is_empty() {
+            current_len = rest.len();
+            current.extend(rest);
+        } else if current_len + 1 + rest.len() <= width {
+            current.push(' ');
+            current_len += 1 + rest.len();
+            current.extend(rest);
+        } else {
+            lines.push(std::mem::take(&mut current));
+            current_len = rest.len();
+            current.extend(rest);
+        }
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+pub fn count_char(s: &str, c: char) -> usize {
+    s.chars().filter(|item| *item == c).count()
+}
+
+// Un índice desordenado o que no cae en un límite de char devuelve un vector vacío
+pub fn split_at_indices<'a>(s: &'a str, indices: &[usize]) -> Vec<&'a str> {
+    let mut pieces = Vec::with_capacity(indices.len() + 1);
+    let mut start = 0;
+    for &index in indices {
+        if index < start || !s.is_char_boundary(index) {
+            return Vec::new();
+        }
+        pieces.push(&s[start..index]);
+        start = index;
+    }
+    pieces.push(&s[start..]);
+    pieces
+}
+
+pub fn replace_char_range(s: &str, start: usize, end: usize, with: &str) -> Option<String> {
+    if start > end {
+        return None;
+    }
+    let start = char_to_byte(s, start)?;
+    let end = char_to_byte(s, end)?;
+
+    let mut result = String::with_capacity(s.len() - (end - start) + with.len());
+    result.push_str(&s[..start]);
+    result.push_str(with);
+    result.push_str(&s[end..]);
+    Some(result)
+}
+
+// Offset en bytes del char número `index`; `index` igual al número de chars es el final
+fn char_to_byte(s: &str, index: usize) -> Option<usize> {
+    s.char_indices()
+        .map(|(i, _)| i)
+        .chain(std::iter::once(s.len()))
+        .nth(index)
+}
+
+pub fn count_lines_matching(s: &str, pred: impl Fn(&str) -> bool) -> usize {
+    s.lines().filter(|line| pred(line)).count()
+}
+
+pub fn grep<'a>(s: &'a str, needle: &str) -> Vec<(usize, &'a str)> {
+    s.lines()
+        .enumerate()
+        .filter(|(_, line)| line.contains(needle))
+        .map(|(i, line)| (i + 1, line))
+        .collect()
+}
+
+// Devuelve `None` en cuanto la distancia supera `max`, sin terminar la matriz
+pub fn levenshtein_within(a: &str, b: &str, max: usize) -> Option<usize> {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    if a.len().abs_diff(b.len()) > max {
+        return None;
+    }
+
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for (i, ca) in a.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cost = if ca == cb { 0 } else { 1 };
+            current[j + 1] = (previous[j] + cost)
+                .min(previous[j + 1] + 1)
+                .min(current[j] + 1);
+        }
+        // Ninguna celda de la fila puede decrecer después, así que el mínimo es una cota inferior
+        if current.iter().min().is_some_and(|&min| min > max) {
+            return None;
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    let distance = previous[b.len()];
+    (distance <= max).then_some(distance)
+}
+
+// El último campo se queda con el resto de la línea, sea cual sea su ancho
+pub fn fixed_width_fields<'a>(line: &'a str, widths: &[usize]) -> Vec<&'a str> {
+    let mut fields = Vec::with_capacity(widths.len());
+    let mut rest = line;
+    for (i, &width) in widths.iter().enumerate() {
+        if i == widths.len() - 1 {
+            fields.push(rest.trim());
+            break;
+        }
+        let end = rest
+            .char_indices()
+            .nth(width)
+            .map_or(rest.len(), |(index, _)| index);
+        fields.push(rest[..end].trim());
+        rest = &rest[end..];
+    }
+    fields
+}
+
+// Sin `\r` en la entrada no hace falta copiar nada
+pub fn normalize_newlines(s: &str) -> Cow<'_, str> {
+    if !s.contains('\r') {
+        return Cow::Borrowed(s);
+    }
+    Cow::Owned(s.replace("\r\n", "\n").replace('\r', "\n"))
+}
+
+pub fn match_ranges(s: &str, target: &str) -> Vec<std::ops::Range<usize>> {
+    word_offsets(s)
+        .filter(|(_, word)| *word == target)
+        .map(|(offset, word)| offset..offset + word.len())
+        .collect()
+}
+
+pub fn strip_control_chars(s: &str) -> String {
+    s.chars()
+        .filter(|&c| !c.is_control() || c == '\n' || c == '\t')
+        .collect()
+}
+
+#[cfg(feature = "unicode")]
+pub fn wrap_graphemes(s: &str, width: usize) -> Vec<String> {
+    wrap_measured(s, width, |word| word.graphemes(true).count())
+}
+
+// Ajuste voraz por palabras, midiendo cada palabra con `measure`
+fn wrap_measured(s: &str, width: usize, measure: impl Fn(&str) -> usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    let mut current_width = 0;
+    for word in s.split_whitespace() {
+        let word_width = measure(word);
+        if current.is_empty() {
+            current.push_str(word);
+            current_width = word_width;
+        } else if current_width + 1 + word_width <= width {
+            current.push(' ');
+            current.push_str(word);
+            current_width += 1 + word_width;
+        } else {
+            lines.push(std::mem::replace(&mut current, word.to_string()));
+            current_width = word_width;
+        }
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+pub fn replace_first_word(s: &str, with: &str) -> String {
+    match word_offsets(s).next() {
+        Some((offset, word)) => format!("{}{}{}", &s[..offset], with, &s[offset + word.len()..]),
+        None => with.to_string(),
+    }
+}
+
+pub fn count_trailing(s: &str, c: char) -> usize {
+    s.chars().rev().take_while(|item| *item == c).count()
+}
+
+// Si un char no cabe en `max_bytes` se entrega solo, para no partirlo ni quedarse en un bucle
+pub fn chunk_by_bytes(s: &str, max_bytes: usize) -> Vec<&str> {
+    let mut pieces = Vec::new();
+    let mut rest = s;
+    while !rest.is_empty() {
+        let mut end = max_bytes.min(rest.len());
+        while !rest.is_char_boundary(end) {
+            end -= 1;
+        }
+        if end == 0 {
+            end = rest.chars().next().map_or(rest.len(), char::len_utf8);
+        }
+        pieces.push(&rest[..end]);
+        rest = &rest[end..];
+    }
+    pieces
+}
+
+#[cfg(feature = "unicode")]
+pub fn count_emoji(s: &str) -> usize {
+    s.graphemes(true).filter(|cluster| is_emoji_cluster(cluster)).count()
+}
+
+// Un grafema es emoji si tiene un char que se muestra como emoji por defecto (Emoji_Presentation),
+// o si pide la presentación emoji con U+FE0F sobre un char que la admite ("©\u{FE0F}"), o si es un keycap
+#[cfg(feature = "unicode")]
+fn is_emoji_cluster(cluster: &str) -> bool {
+    let Some(first) = cluster.chars().next() else {
+        return false;
+    };
+    if cluster.chars().any(is_emoji_presentation) {
+        return true;
+    }
+    if cluster.contains('\u{20E3}') {
+        return matches!(first, '#' | '*' | '0'..='9');
+    }
+    cluster.contains('\u{FE0F}') && is_emoji_text_default(first)
+}
+
+// Emoji_Presentation=Yes según emoji-data.txt: se dibujan como emoji sin selector de variación
+#[cfg(feature = "unicode")]
+fn is_emoji_presentation(c: char) -> bool {
+    matches!(
+        c,
+        '\u{231A}'..='\u{231B}'
+            | '\u{23E9}'..='\u{23EC}'
+            | '\u{23F0}'
+            | '\u{23F3}'
+            | '\u{25FD}'..='\u{25FE}'
+            | '\u{2614}'..='\u{2615}'
+            | '\u{2648}'..='\u{2653}'
+            | '\u{267F}'
+            | '\u{2693}'
+            | '\u{26A1}'
+            | '\u{26AA}'..='\u{26AB}'
+            | '\u{26BD}'..='\u{26BE}'
+            | '\u{26C4}'..='\u{26C5}'
+            | '\u{26CE}'
+            | '\u{26D4}'
+            | '\u{26EA}'
+            | '\u{26F2}'..='\u{26F3}'
+            | '\u{26F5}'
+            | '\u{26FA}'
+            | '\u{26FD}'
+            | '\u{2705}'
+            | '\u{270A}'..='\u{270B}'
+            | '\u{2728}'
+            | '\u{274C}'
+            | '\u{274E}'
+            | '\u{2753}'..='\u{2755}'
+            | '\u{2757}'
+            | '\u{2795}'..='\u{2797}'
+            | '\u{27B0}'
+            | '\u{27BF}'
+            | '\u{2B1B}'..='\u{2B1C}'
+            | '\u{2B50}'
+            | '\u{2B55}'
+            | '\u{1F004}'
+            | '\u{1F0CF}'
+            | '\u{1F18E}'
+            | '\u{1F191}'..='\u{1F19A}'
+            | '\u{1F1E6}'..='\u{1F1FF}' // indicadores regionales (banderas)
+            | '\u{1F201}'
+            | '\u{1F21A}'
+            | '\u{1F22F}'
+            | '\u{1F232}'..='\u{1F236}'
+            | '\u{1F238}'..='\u{1F23A}'
+            | '\u{1F250}'..='\u{1F251}'
+            | '\u{1F300}'..='\u{1F320}'
+            | '\u{1F32D}'..='\u{1F335}'
+            | '\u{1F337}'..='\u{1F37C}'
+            | '\u{1F37E}'..='\u{1F393}'
+            | '\u{1F3A0}'..='\u{1F3CA}'
+            | '\u{1F3CF}'..='\u{1F3D3}'
+            | '\u{1F3E0}'..='\u{1F3F0}'
+            | '\u{1F3F4}'
+            | '\u{1F3F8}'..='\u{1F43E}'
+            | '\u{1F440}'
+            | '\u{1F442}'..='\u{1F4FC}'
+            | '\u{1F4FF}'..='\u{1F53D}'
+            | '\u{1F54B}'..='\u{1F54E}'
+            | '\u{1F550}'..='\u{1F567}'
+            | '\u{1F57A}'
+            | '\u{1F595}'..='\u{1F596}'
+            | '\u{1F5A4}'
+            | '\u{1F5FB}'..='\u{1F64F}'
+            | '\u{1F680}'..='\u{1F6C5}'
+            | '\u{1F6CC}'
+            | '\u{1F6D0}'..='\u{1F6D2}'
+            | '\u{1F6D5}'..='\u{1F6D7}'
+            | '\u{1F6DC}'..='\u{1F6DF}'
+            | '\u{1F6EB}'..='\u{1F6EC}'
+            | '\u{1F6F4}'..='\u{1F6FC}'
+            | '\u{1F7E0}'..='\u{1F7EB}'
+            | '\u{1F7F0}'
+            | '\u{1F90C}'..='\u{1F93A}'
+            | '\u{1F93C}'..='\u{1F945}'
+            | '\u{1F947}'..='\u{1F9FF}'
+            | '\u{1FA70}'..='\u{1FA7C}'
+            | '\u{1FA80}'..='\u{1FA89}'
+            | '\u{1FA8F}'..='\u{1FAC6}'
+            | '\u{1FACE}'..='\u{1FADC}'
+            | '\u{1FADF}'..='\u{1FAE9}'
+            | '\u{1FAF0}'..='\u{1FAF8}'
+    )
+}
+
+// Chars que son texto por defecto pero admiten presentación emoji con U+FE0F (©, ™, ☀, ✔, ...).
+// Cubre los bloques de símbolos y pictogramas, no la lista exacta de la propiedad Emoji
+#[cfg(feature = "unicode")]
+fn is_emoji_text_default(c: char) -> bool {
+    matches!(
+        c,
+        '\u{A9}'
+            | '\u{AE}'
+            | '\u{203C}'
+            | '\u{2049}'
+            | '\u{2122}'
+            | '\u{2139}'
+            | '\u{2194}'..='\u{2199}'
+            | '\u{21A9}'..='\u{21AA}'
+            | '\u{2328}'
+            | '\u{23CF}'
+            | '\u{23ED}'..='\u{23EF}'
+            | '\u{23F1}'..='\u{23F2}'
+            | '\u{23F8}'..='\u{23FA}'
+            | '\u{24C2}'
+            | '\u{25AA}'..='\u{25AB}'
+            | '\u{25B6}'
+            | '\u{25C0}'
+            | '\u{25FB}'..='\u{25FC}'
+            | '\u{2600}'..='\u{27BF}'
+            | '\u{2934}'..='\u{2935}'
+            | '\u{2B05}'..='\u{2B07}'
+            | '\u{3030}'
+            | '\u{303D}'
+            | '\u{3297}'
+            | '\u{3299}'
+            | '\u{1F000}'..='\u{1FAFF}'
+    )
+}
+
+pub fn words_with_utf16_offsets(s: &str) -> Vec<(&str, usize)> {
+    let mut byte_offset = 0;
+    let mut utf16_offset = 0;
+    word_offsets(s)
+        .map(|(offset, word)| {
+            utf16_offset += s[byte_offset..offset].encode_utf16().count();
+            byte_offset = offset;
+            (word, utf16_offset)
+        })
+        .collect()
+}
+
+pub fn is_mixed_case(s: &str) -> bool {
+    s.chars().any(char::is_uppercase) && s.chars().any(char::is_lowercase)
+}
+
+pub fn wrap_visible(s: &str, width: usize) -> Vec<String> {
+    wrap_measured(s, width, visible_width)
+}
+
+// Chars visibles, sin contar secuencias ANSI del tipo `ESC [ ... letra`
+fn visible_width(s: &str) -> usize {
+    let mut width = 0;
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            if chars.next() == Some('[') {
+                for c in chars.by_ref() {
+                    if ('\x40'..='\x7e').contains(&c) {
+                        break;
+                    }
+                }
+            }
+        } else {
+            width += 1;
+        }
+    }
+    width
+}
+
+// Un rango invertido o que no cae en límites de char devuelve 0
+pub fn word_count_in_range(s: &str, range: std::ops::Range<usize>) -> usize {
+    if range.start > range.end || !s.is_char_boundary(range.start) || !s.is_char_boundary(range.end) {
+        return 0;
+    }
+    word_offsets(s)
+        .filter(|(offset, _)| range.contains(offset))
+        .count()
+}
+
+pub fn to_constant_case(s: &str) -> String {
+    case_words(s)
+        .iter()
+        .map(|word| word.to_uppercase())
+        .collect::<Vec<String>>()
+        .join("_")
+}
+
+// Separa un identificador en palabras: por separadores (espacio, `_`, `-`, ...) y por
+// cambios de minúscula a mayúscula, manteniendo juntos los acrónimos ("HTTPRequest" -> HTTP, Request)
+fn case_words(s: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    for part in s.split(|c: char| !c.is_alphanumeric()).filter(|part| !part.is_empty()) {
+        let chars: Vec<(usize, char)> = part.char_indices().collect();
+        let mut start = 0;
+        for i in 1..chars.len() {
+            let (index, c) = chars[i];
+            let previous = chars[i - 1].1;
+            let next_is_lower = chars.get(i + 1).is_some_and(|(_, next)| next.is_lowercase());
+            let boundary = c.is_uppercase()
+                && (previous.is_lowercase()
+                    || previous.is_numeric()
+                    || (previous.is_uppercase() && next_is_lower));
+            if boundary {
+                words.push(&part[start..index]);
+                start = index;
+            }
+        }
+        words.push(&part[start..]);
+    }
+    words
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Case {
+    Snake,
+    Kebab,
+    Camel,
+    Pascal,
+    Upper,
+    Lower,
+}
+
+// Clasifica un único identificador; vacío, con espacios o con mezcla de estilos devuelve `None`
+pub fn detect_case(s: &str) -> Option<Case> {
+    if s.is_empty() || s.chars().any(|c| !c.is_alphanumeric() && c != '_' && c != '-') {
+        return None;
+    }
+    let has_underscore = s.contains('_');
+    let has_hyphen = s.contains('-');
+    let has_upper = s.chars().any(char::is_uppercase);
+    let has_lower = s.chars().any(char::is_lowercase);
+    let first_upper = s.chars().next().is_some_and(char::is_uppercase);
+    // Sin letras ("_", "123") no hay estilo que detectar
+    if !has_upper && !has_lower {
+        return None;
+    }
+
+    match (has_underscore, has_hyphen) {
+        (true, true) => None,
+        (_, _) if has_upper && !has_lower => Some(Case::Upper),
+        (true, false) if !has_upper => Some(Case::Snake),
+        (false, true) if !has_upper => Some(Case::Kebab),
+        (false, false) if !has_upper => Some(Case::Lower),
+        (false, false) if first_upper => Some(Case::Pascal),
+        (false, false) => Some(Case::Camel),
+        _ => None,
+    }
+}
+
+pub fn longest_word_with_prefix<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    let mut longest: Option<(&str, usize)> = None;
+    for word in s.split_whitespace().filter(|word| word.starts_with(prefix)) {
+        let length = word.chars().count();
+        // Sólo reemplaza si es estrictamente más larga, así los empates se quedan con la primera
+        if longest.is_none_or(|(_, best)| length > best) {
+            longest = Some((word, length));
+        }
+    }
+    longest.map(|(word, _)| word)
+}
+
+pub fn to_dot_case(s: &str) -> String {
+    case_words(s)
+        .iter()
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<String>>()
+        .join(".")
+}
+
+pub fn word_bounds_at(s: &str, byte_offset: usize) -> Option<std::ops::Range<usize>> {
+    word_offsets(s)
+        .map(|(offset, word)| offset..offset + word.len())
+        .find(|range| range.contains(&byte_offset))
+}
+
+pub fn reverse_each_word(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut last = 0;
+    for (offset, word) in word_offsets(s) {
+        result.push_str(&s[last..offset]);
+        result.extend(word.chars().rev());
+        last = offset + word.len();
+    }
+    result.push_str(&s[last..]);
+    result
+}
+
+pub fn scan_tokens(s: &str, mut on_token: impl FnMut(&str, usize)) {
+    for (offset, word) in word_offsets(s) {
+        on_token(word, offset);
+    }
+}
+
+// Offset en bytes y char del primer `()[]{}` sin pareja o que cierra el grupo equivocado
+pub fn first_unmatched_bracket(s: &str) -> Option<(usize, char)> {
+    let mut open: Vec<(usize, char)> = Vec::new();
+    for (i, c) in s.char_indices() {
+        let expected = match c {
+            '(' | '[' | '{' => {
+                open.push((i, c));
+                continue;
+            }
+            ')' => '(',
+            ']' => '[',
+            '}' => '{',
+            _ => continue,
+        };
+        match open.pop() {
+            Some((_, opening)) if opening == expected => {}
+            _ => return Some((i, c)),
+        }
+    }
+    // La apertura más antigua que quedó sin cerrar
+    open.first().copied()
+}
+
+pub fn collapse_whitespace_to(s: &str, replacement: char) -> String {
+    let mut result = String::with_capacity(s.len());
+    for word in s.split_whitespace() {
+        if !result.is_empty() {
+            result.push(replacement);
+        }
+        result.push_str(word);
+    }
+    result
+}
+
+// El cuerpo es todo lo que sigue a la primera línea en blanco; sin ella, queda vacío
+pub fn split_title_body(s: &str) -> (&str, &str) {
+    let title = s.lines().next().unwrap_or("");
+    let mut offset = 0;
+    for line in s.split_inclusive('\n') {
+        offset += line.len();
+        if line.trim().is_empty() {
+            return (title, &s[offset..]);
+        }
+    }
+    (title, "")
+}
+
+// Triples `(char, offset en bytes, bytes que ocupa)` en una sola pasada
+pub fn chars_with_byte_len(s: &str) -> impl Iterator<Item = (char, usize, usize)> + '_ {
+    s.char_indices().map(|(i, c)| (c, i, c.len_utf8()))
+}
+
+pub fn ensure_suffix<'a>(s: &'a str, suffix: &str) -> Cow<'a, str> {
+    if s.ends_with(suffix) {
+        Cow::Borrowed(s)
+    } else {
+        Cow::Owned(format!("{}{}", s, suffix))
+    }
+}
+
+pub fn ensure_prefix<'a>(s: &'a str, prefix: &str) -> Cow<'a, str> {
+    if s.starts_with(prefix) {
+        Cow::Borrowed(s)
+    } else {
+        Cow::Owned(format!("{}{}", prefix, s))
+    }
+}
+
+pub fn byte_range_to_char_range(
+    s: &str,
+    range: std::ops::Range<usize>,
+) -> Option<std::ops::Range<usize>> {
+    if range.start > range.end || !s.is_char_boundary(range.start) || !s.is_char_boundary(range.end) {
+        return None;
+    }
+    let start = s[..range.start].chars().count();
+    let end = start + s[range].chars().count();
+    Some(start..end)
+}
+
+pub fn split_once_any<'a>(s: &'a str, delims: &str) -> Option<(&'a str, char, &'a str)> {
+    let (i, c) = s.char_indices().find(|(_, c)| delims.contains(*c))?;
+    Some((&s[..i], c, &s[i + c.len_utf8()..]))
+}
+
+pub fn word_ranges_utf16(s: &str) -> Vec<(usize, usize)> {
+    words_with_utf16_offsets(s)
+        .into_iter()
+        .map(|(word, start)| (start, start + word.encode_utf16().count()))
+        .collect()
+}
+
+// Conserva los candidatos que contienen `query` como subsecuencia (sin distinguir mayúsculas) y los
+// ordena por puntuación: gana la coincidencia que empieza antes y deja menos huecos entre letras
+pub fn fuzzy_filter<'a>(candidates: &[&'a str], query: &str) -> Vec<&'a str> {
+    let query: Vec<char> = query.chars().flat_map(char::to_lowercase).collect();
+    let mut scored: Vec<(usize, &str)> = candidates
+        .iter()
+        .filter_map(|candidate| fuzzy_score(candidate, &query).map(|score| (score, *candidate)))
+        .collect();
+    // `sort_by_key` es estable: con la misma puntuación se respeta el orden original
+    scored.sort_by_key(|(score, _)| *score);
+    scored.into_iter().map(|(_, candidate)| candidate).collect()
+}
+
+// Posición de la primera letra encontrada más los huecos entre letras; menor es mejor
+fn fuzzy_score(candidate: &str, query: &[char]) -> Option<usize> {
+    let mut remaining = query.iter().peekable();
+    let mut first = None;
+    let mut gaps = 0;
+    let mut last = 0;
+    for (i, c) in candidate.chars().flat_map(char::to_lowercase).enumerate() {
+        let Some(&&wanted) = remaining.peek() else {
+            break;
+        };
+        if c == wanted {
+            match first {
+                None => first = Some(i),
+                Some(_) => gaps += i - last - 1,
+            }
+            last = i;
+            remaining.next();
+        }
+    }
+    if remaining.peek().is_some() {
+        return None;
+    }
+    Some(first.unwrap_or(0) + gaps)
+}
+
+// Lo mismo que `s.len()`: bytes en UTF-8, no letras
+pub fn byte_length(s: &str) -> usize {
+    s.len()
+}
+
+// Número de chars; "café" tiene 4 chars pero ocupa 5 bytes
+pub fn char_length(s: &str) -> usize {
+    s.chars().count()
+}
+
+// Bloques separados por una o más líneas en blanco (o sólo con espacios), cada uno sin espacios alrededor
+pub fn records(s: &str) -> impl Iterator<Item = &str> {
+    let mut rest = s;
+    std::iter::from_fn(move || {
+        let start = rest.trim_start();
+        if start.is_empty() {
+            return None;
+        }
+        let mut end = 0;
+        for line in start.split_inclusive('\n') {
+            if line.trim().is_empty() {
+                break;
+            }
+            end += line.len();
+        }
+        rest = &start[end..];
+        Some(start[..end].trim_end())
+    })
+}
+
+// Conjunto de grupos de `k` palabras consecutivas unidas por un espacio; vacío si hay menos de `k` palabras
+pub fn shingles(s: &str, k: usize) -> HashSet<String> {
+    if k == 0 {
+        return HashSet::new();
+    }
+    let words: Vec<&str> = s.split_whitespace().collect();
+    words.windows(k).map(|window| window.join(" ")).collect()
+}
+
+// Intersección entre unión de los shingles de ambos textos: 1.0 si son iguales, 0.0 si no comparten ninguno.
+// Si ninguno de los dos tiene `k` palabras no hay nada que comparar y devuelve 0.0.
+pub fn jaccard_similarity(a: &str, b: &str, k: usize) -> f64 {
+    let a = shingles(a, k);
+    let b = shingles(b, k);
+    let union = a.union(&b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(&b).count() as f64 / union as f64
+}
+
+// Espacios iniciales de cada línea, contando cada tabulador como `tab_width` (1 para contarlo como un char).
+// Una línea en blanco cuenta como 0.
+pub fn indent_widths(s: &str, tab_width: usize) -> Vec<usize> {
+    s.lines()
+        .map(|line| {
+            if line.trim().is_empty() {
+                return 0;
+            }
+            line.chars()
+                .take_while(|c| c.is_whitespace())
+                .map(|c| if c == '\t' { tab_width } else { 1 })
+                .sum()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn map_lines_keeps_trailing_newline() {
+        let n = Cell::new(0);
+        let numbered = map_lines("uno\ndos\ntres\n", |line| {
+            n.set(n.get() + 1);
+            format!("{} {}", n.get(), line)
+        });
+        assert_eq!(numbered, "1 uno\n2 dos\n3 tres\n");
+        assert_eq!(map_lines("a\nb", |line| line.to_uppercase()), "A\nB");
+    }
+
+    #[test]
+    fn number_lines_from_start() {
+        let numbered = number_lines("uno\ndos\ntres", 1);
+        assert_eq!(numbered, "1\tuno\n2\tdos\n3\ttres");
+        assert_eq!(number_lines("uno\ndos\ntres\n", 10), "10\tuno\n11\tdos\n12\ttres\n");
+    }
+
+    #[test]
+    fn char_index_of_counts_chars() {
+        let s = "añob";
+        assert_eq!(s.find('o'), Some(3)); // byte
+        assert_eq!(char_index_of(s, 'o'), Some(2)); // char
+        assert_eq!(char_index_of(s, 'z'), None);
+    }
+
+    #[test]
+    fn pad_words_to_width() {
+        let padded = pad_words("a bb ccccc", 3);
+        assert_eq!(padded, "a   bb  ccccc");
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn graphemes_keep_combining_marks() {
+        let clusters = graphemes("e\u{301}🇵🇪!");
+        assert_eq!(clusters, vec!["e\u{301}", "🇵🇪", "!"]);
+    }
+
+    #[test]
+    fn trim_prefix_only_when_present() {
+        assert_eq!(trim_prefix("https://rust-lang.org", "https://"), "rust-lang.org");
+        assert_eq!(trim_prefix("rust-lang.org", "https://"), "rust-lang.org");
+    }
+
+    #[test]
+    fn count_syllables_by_vowel_groups() {
+        assert_eq!(count_syllables("apple"), 2);
+        assert_eq!(count_syllables("the"), 1);
+        assert_eq!(count_syllables("strength"), 1);
+        assert_eq!(count_syllables("make"), 1);
+    }
+
+    #[test]
+    fn flesch_reading_ease_simple_sentence() {
+        let score = flesch_reading_ease("The cat sat on the mat.");
+        assert!(score > 90.0 && score <= 121.22);
+        assert_eq!(flesch_reading_ease(""), 0.0);
+    }
+
+    #[test]
+    fn flesch_reading_ease_ignores_numeric_tokens() {
+        assert_eq!(flesch_reading_ease("123 456."), 0.0);
+        assert_eq!(
+            flesch_reading_ease("The cat sat on the mat 42."),
+            flesch_reading_ease("The cat sat on the mat.")
+        );
+    }
+
+    #[test]
+    fn shell_split_quotes_and_escapes() {
+        assert_eq!(shell_split(r#"foo "bar baz" qux"#), vec!["foo", "bar baz", "qux"]);
+        assert_eq!(shell_split(r"cp my\ file.txt 'otro dir'"), vec!["cp", "my file.txt", "otro dir"]);
+        assert_eq!(shell_split(r#"echo """#), vec!["echo", ""]);
+    }
+
+    #[test]
+    fn redact_numbers_per_digit() {
+        assert_eq!(redact_numbers("id 12345 ok", '#'), "id ##### ok");
+        assert_eq!(redact_numbers("a1b22", '*'), "a*b**");
+    }
+
+    #[test]
+    fn word_after_whole_words() {
+        assert_eq!(word_after("user alice role admin", "role"), Some("admin"));
+        assert_eq!(word_after("user alice role admin", "admin"), None);
+        assert_eq!(word_after("username alice", "user"), None);
+    }
+
+    #[test]
+    fn words_between_markers() {
+        assert_eq!(words_between("BEGIN hola  mundo END resto", "BEGIN", "END"), Some("hola  mundo"));
+        assert_eq!(words_between("BEGIN hola mundo", "BEGIN", "END"), None);
+    }
+
+    #[test]
+    fn fold_accents_to_ascii() {
+        assert_eq!(fold_accents("café"), "cafe");
+        assert_eq!(fold_accents("Ñandú pingüino"), "Nandu pinguino");
+        assert_eq!(fold_accents("hello world"), "hello world");
+    }
+
+    #[test]
+    fn contains_ignores_case_and_accents() {
+        assert!(contains_ci_accent_insensitive("Visit the Café", "cafe"));
+        assert!(contains_ci_accent_insensitive("Visit the Café", "CAFÉ"));
+        assert!(!contains_ci_accent_insensitive("Visit the Café", "tea"));
+    }
+
+    #[test]
+    fn dedup_chars_keeps_first() {
+        assert_eq!(dedup_chars("mississippi"), "misp");
+        assert_eq!(dedup_chars("rust"), "rust");
+    }
+
+    #[test]
+    fn render_table_aligns_columns() {
+        let table = render_table(&[vec!["id", "nombre", "rol"], vec!["1", "Ana", "administradora"]]);
+        assert_eq!(table, "id  nombre  rol\n1   Ana     administradora");
+    }
+
+    #[test]
+    fn first_non_whitespace_offset() {
+        assert_eq!(first_non_whitespace("   fn main()"), Some(3));
+        assert_eq!(first_non_whitespace(" \t\n"), None);
+    }
+
+    #[test]
+    fn wrap_hyphenate_long_words() {
+        let lines = wrap_hyphenate("un supercalifragilistico dia", 8);
+        assert_eq!(lines, vec!["un", "superca-", "lifragi-", "listico", "dia"]);
+        assert_eq!(wrap_hyphenate("hola que tal", 8), vec!["hola que", "tal"]);
+    }
+
+    #[test]
+    fn count_char_multibyte() {
+        assert_eq!(count_char("banana", 'a'), 3);
+        assert_eq!(count_char("año tras año", 'ñ'), 2);
+    }
+
+    #[test]
+    fn split_at_indices_checks_boundaries() {
+        assert_eq!(split_at_indices("hello world", &[5, 6]), vec!["hello", " ", "world"]);
+        assert_eq!(split_at_indices("héllo", &[2]), Vec::<&str>::new()); // 2 cae dentro de `é`
+        assert_eq!(split_at_indices("hello", &[3, 1]), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn replace_char_range_by_chars() {
+        assert_eq!(replace_char_range("año nuevo", 0, 3, "día"), Some(String::from("día nuevo")));
+        assert_eq!(replace_char_range("año", 2, 1, "x"), None);
+        assert_eq!(replace_char_range("año", 1, 9, "x"), None);
+    }
+
+    #[test]
+    fn count_lines_matching_predicate() {
+        let log = "INFO inicio\nERROR disco\nINFO fin\nERROR red";
+        assert_eq!(count_lines_matching(log, |line| line.contains("ERROR")), 2);
+    }
+
+    #[test]
+    fn grep_numbers_lines_from_one() {
+        let log = "INFO inicio\nERROR disco\nINFO fin\nERROR red";
+        assert_eq!(grep(log, "ERROR"), vec![(2, "ERROR disco"), (4, "ERROR red")]);
+        assert!(grep(log, "WARN").is_empty());
+    }
+
+    #[test]
+    fn levenshtein_within_limit() {
+        assert_eq!(levenshtein_within("kitten", "sitting", 3), Some(3));
+        assert_eq!(levenshtein_within("kitten", "sitting", 2), None);
+        assert_eq!(levenshtein_within("a", "abcdef", 2), None);
+        assert_eq!(levenshtein_within("año", "ano", 1), Some(1));
+    }
+
+    #[test]
+    fn fixed_width_fields_trims() {
+        let record = "0042José      Lima   Perú";
+        assert_eq!(fixed_width_fields(record, &[4, 10, 2]), vec!["0042", "José", "Lima   Perú"]);
+    }
+
+    #[test]
+    fn normalize_newlines_to_lf() {
+        assert_eq!(normalize_newlines("a\r\nb\rc"), "a\nb\nc");
+        assert!(matches!(normalize_newlines("a\nb"), Cow::Borrowed("a\nb")));
+    }
+
+    #[test]
+    fn match_ranges_whole_words() {
+        let sentence = "el gato y el gatito";
+        let ranges = match_ranges(sentence, "el");
+        assert_eq!(ranges, vec![0..2, 10..12]);
+        assert!(ranges.iter().all(|range| &sentence[range.clone()] == "el"));
+    }
+
+    #[test]
+    fn strip_control_chars_keeps_newline_and_tab() {
+        assert_eq!(strip_control_chars("a\0b\x07c\n\td\u{85}"), "abc\n\td");
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn wrap_graphemes_counts_clusters() {
+        let lines = wrap_graphemes("cafe\u{301} cafe\u{301} cafe\u{301}", 9);
+        assert_eq!(lines, vec!["cafe\u{301} cafe\u{301}", "cafe\u{301}"]);
+    }
+
+    #[test]
+    fn replace_first_word_keeps_rest() {
+        assert_eq!(replace_first_word("ls  -la /tmp", "exa"), "exa  -la /tmp");
+        assert_eq!(replace_first_word("ls", "exa"), "exa");
+    }
+
+    #[test]
+    fn count_trailing_chars() {
+        assert_eq!(count_trailing("abc!!!", '!'), 3);
+        assert_eq!(count_trailing("abc", '!'), 0);
+    }
+
+    #[test]
+    fn chunk_by_bytes_on_char_boundaries() {
+        let pieces = chunk_by_bytes("añoñú", 3);
+        assert_eq!(pieces, vec!["añ", "oñ", "ú"]);
+        assert_eq!(pieces.concat(), "añoñú");
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn count_emoji_joins_zwj_sequences() {
+        let post = "hola 👋 familia 👨\u{200d}👩\u{200d}👧 🇵🇪!";
+        assert_eq!(count_emoji(post), 3);
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn count_emoji_skips_text_symbols() {
+        assert_eq!(count_emoji("✓ done"), 0);
+        assert_eq!(count_emoji("☐ ⬀ © ☀"), 0); // sin U+FE0F se muestran como texto
+        assert_eq!(count_emoji("⌚ ⏰ ✅"), 3);
+        assert_eq!(count_emoji("©\u{FE0F} ☀\u{FE0F}"), 2);
+        assert_eq!(count_emoji("pulsa 1\u{FE0F}\u{20E3} o #\u{20E3}"), 2); // keycaps
+    }
+
+    #[test]
+    fn words_with_utf16_offsets_outside_bmp() {
+        assert_eq!(words_with_utf16_offsets("𝄞 clave sol"), vec![("𝄞", 0), ("clave", 3), ("sol", 9)]);
+    }
+
+    #[test]
+    fn is_mixed_case_needs_both() {
+        assert!(is_mixed_case("aB"));
+        assert!(!is_mixed_case("abc"));
+        assert!(!is_mixed_case("ABC"));
+        assert!(is_mixed_case("Ñandú"));
+    }
+
+    #[test]
+    fn wrap_visible_ignores_ansi() {
+        let colored = "\x1b[31mrojo\x1b[0m y \x1b[32mverde\x1b[0m";
+        let lines = wrap_visible(colored, 6);
+        assert_eq!(lines, vec!["\x1b[31mrojo\x1b[0m y", "\x1b[32mverde\x1b[0m"]);
+    }
+
+    #[test]
+    fn word_count_in_range_by_start() {
+        let edited = "uno dos tres cuatro";
+        assert_eq!(word_count_in_range(edited, 2..12), 2); // "dos" y "tres"
+        assert_eq!(word_count_in_range(edited, 0..edited.len()), 4);
+        assert_eq!(word_count_in_range("año", 2..3), 0); // 2 no es límite de char
+    }
+
+    #[test]
+    fn to_constant_case_splits_acronyms() {
+        assert_eq!(to_constant_case("fooBar"), "FOO_BAR");
+        assert_eq!(to_constant_case("hello world"), "HELLO_WORLD");
+        assert_eq!(to_constant_case("parseHTTPRequest-v2"), "PARSE_HTTP_REQUEST_V2");
+    }
+
+    #[test]
+    fn detect_case_styles() {
+        assert_eq!(detect_case("foo_bar"), Some(Case::Snake));
+        assert_eq!(detect_case("foo-bar"), Some(Case::Kebab));
+        assert_eq!(detect_case("fooBar"), Some(Case::Camel));
+        assert_eq!(detect_case("FooBar"), Some(Case::Pascal));
+        assert_eq!(detect_case("FOO_BAR"), Some(Case::Upper));
+        assert_eq!(detect_case("foo"), Some(Case::Lower));
+        assert_eq!(detect_case("foo_Bar-baz"), None);
+        assert_eq!(detect_case(""), None);
+        assert_eq!(detect_case("_"), None);
+        assert_eq!(detect_case("__"), None);
+        assert_eq!(detect_case("-"), None);
+        assert_eq!(detect_case("123"), None);
+        assert_eq!(detect_case("foo_2"), Some(Case::Snake));
+    }
+
+    #[test]
+    fn longest_word_with_prefix_first_on_tie() {
+        let dictionary = "pro programa prosa programación producto";
+        assert_eq!(longest_word_with_prefix(dictionary, "pro"), Some("programación"));
+        assert_eq!(longest_word_with_prefix("prosa prado", "pr"), Some("prosa")); // empate: la primera
+        assert_eq!(longest_word_with_prefix(dictionary, "x"), None);
+    }
+
+    #[test]
+    fn to_dot_case_lowercases() {
+        assert_eq!(to_dot_case("fooBar"), "foo.bar");
+        assert_eq!(to_dot_case("  Hola  Mundo "), "hola.mundo");
+    }
+
+    #[test]
+    fn word_bounds_at_cursor() {
+        let line = "let año = 5;";
+        assert_eq!(word_bounds_at(line, 5), Some(4..8)); // dentro de "año"
+        assert_eq!(word_bounds_at(line, 3), None); // espacio
+        assert_eq!(word_bounds_at(line, 99), None);
+    }
+
+    #[test]
+    fn reverse_each_word_keeps_spacing() {
+        assert_eq!(reverse_each_word("abc def"), "cba fed");
+        assert_eq!(reverse_each_word(" año  luz"), " oña  zul");
+    }
+
+    #[test]
+    fn scan_tokens_reports_offsets() {
+        let mut tokens = Vec::new();
+        scan_tokens("  uno dos\ttres", |word, offset| tokens.push((word.to_string(), offset)));
+        assert_eq!(
+            tokens,
+            vec![(String::from("uno"), 2), (String::from("dos"), 6), (String::from("tres"), 10)]
+        );
+    }
+
+    #[test]
+    fn first_unmatched_bracket_position() {
+        assert_eq!(first_unmatched_bracket("f(a[1]) {}"), None);
+        assert_eq!(first_unmatched_bracket("f(a[1)]"), Some((5, ')'))); // cierre que no corresponde
+        assert_eq!(first_unmatched_bracket("{ f(x) "), Some((0, '{'))); // apertura sin cerrar
+        assert_eq!(first_unmatched_bracket("ñ)"), Some((2, ')')));
+    }
+
+    #[test]
+    fn collapse_whitespace_to_char() {
+        assert_eq!(collapse_whitespace_to("a  b\tc", '_'), "a_b_c");
+        assert_eq!(collapse_whitespace_to("  hola \n mundo  ", '-'), "hola-mundo");
+    }
+
+    #[test]
+    fn split_title_body_like_commit() {
+        let message = "Fix parser\n\nHandle empty input.\nAdd docs.\n";
+        assert_eq!(split_title_body(message), ("Fix parser", "Handle empty input.\nAdd docs.\n"));
+        assert_eq!(split_title_body("Fix parser"), ("Fix parser", ""));
+    }
+
+    #[test]
+    fn chars_with_byte_len_offsets() {
+        let triples: Vec<(char, usize, usize)> = chars_with_byte_len("añ€𝄞").collect();
+        assert_eq!(triples, vec![('a', 0, 1), ('ñ', 1, 2), ('€', 3, 3), ('𝄞', 6, 4)]);
+    }
+
+    #[test]
+    fn ensure_suffix_borrows_when_present() {
+        assert!(matches!(ensure_suffix("linea\n", "\n"), Cow::Borrowed("linea\n")));
+        assert_eq!(ensure_suffix("informe", ".txt"), "informe.txt");
+        assert!(matches!(ensure_suffix("informe", ""), Cow::Borrowed("informe")));
+    }
+
+    #[test]
+    fn ensure_prefix_borrows_when_present() {
+        assert!(matches!(ensure_prefix("https://rust-lang.org", "https://"), Cow::Borrowed(_)));
+        assert_eq!(ensure_prefix("rust-lang.org", "https://"), "https://rust-lang.org");
+    }
+
+    #[test]
+    fn byte_range_to_char_range_checks_boundaries() {
+        assert_eq!(byte_range_to_char_range("año nuevo", 4..9), Some(3..8));
+        assert_eq!(byte_range_to_char_range("año nuevo", 0..4), Some(0..3));
+        assert_eq!(byte_range_to_char_range("año nuevo", 0..2), None); // 2 cae dentro de `ñ`
+    }
+
+    #[test]
+    fn split_once_any_first_delimiter() {
+        assert_eq!(split_once_any("a=b;c", "=;"), Some(("a", '=', "b;c")));
+        assert_eq!(split_once_any("a;b=c", "=;"), Some(("a", ';', "b=c")));
+        assert_eq!(split_once_any("abc", "=;"), None);
+    }
+
+    #[test]
+    fn word_ranges_utf16_units() {
+        assert_eq!(word_ranges_utf16("la 𝄞clave sol"), vec![(0, 2), (3, 10), (11, 14)]);
+    }
+
+    #[test]
+    fn fuzzy_filter_ranks_matches() {
+        let commands = ["Open File", "Format Document", "Find in Files", "Close"];
+        let found = fuzzy_filter(&commands, "fi");
+        assert_eq!(found, vec!["Find in Files", "Open File"]);
+        assert_eq!(fuzzy_filter(&commands, "zz"), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn byte_and_char_length() {
+        assert_eq!("café".len(), 5);
+        assert_eq!(byte_length("café"), 5);
+        assert_eq!(char_length("café"), 4);
+    }
+
+    #[test]
+    fn records_split_on_blank_lines() {
+        let vcards = "NAME: Ana\nTEL: 1\n\n\n  \nNAME: Luis\nTEL: 2\n\n\n";
+        assert_eq!(records(vcards).collect::<Vec<_>>(), vec!["NAME: Ana\nTEL: 1", "NAME: Luis\nTEL: 2"]);
+        assert_eq!(records("NAME: Ana\nTEL: 1").collect::<Vec<_>>(), vec!["NAME: Ana\nTEL: 1"]);
+        assert_eq!(records("\n\n").count(), 0);
+    }
+
+    #[test]
+    fn shingles_and_jaccard() {
+        let expected: HashSet<String> = ["a b", "b c"].iter().map(|s| s.to_string()).collect();
+        assert_eq!(shingles("a b c", 2), expected);
+        assert!(shingles("a", 2).is_empty());
+        assert_eq!(jaccard_similarity("el gato come", "el gato come", 2), 1.0);
+        assert_eq!(jaccard_similarity("el gato come", "un perro ladra", 2), 0.0);
+        // {el gato, gato come} frente a {el gato, gato duerme}: 1 en común de 3
+        let partial = jaccard_similarity("el gato come", "el gato duerme", 2);
+        assert!((partial - 1.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn indent_widths_with_tab_width() {
+        let code = "fn main() {\n    let x = 1;\n\tif x {\n  \t  y();\n\n}";
+        assert_eq!(indent_widths(code, 4), vec![0, 4, 4, 8, 0, 0]);
+        assert_eq!(indent_widths(code, 1), vec![0, 4, 1, 5, 0, 0]);
+    }
+}
@@ -0,0 +1,1004 @@
+use core::cell::OnceCell;
+
+#[cfg(feature = "std")]
+use std::collections::{HashMap, VecDeque};
+#[cfg(feature = "std")]
+use std::convert::Infallible;
+#[cfg(feature = "std")]
+use std::fmt;
+#[cfg(feature = "std")]
+use std::io::{self, BufRead};
+#[cfg(feature = "std")]
+use std::str::FromStr;
+
+// Funciones sobre palabras que devuelven slices del texto original, sin tomar propiedad.
+// Reciben `&str`, así que sirven tanto para `String` (por coerción de desreferencia) como para literales.
+// Las que sólo devuelven slices funcionan sin `std`; las que reservan memoria requieren la feature `std`.
+
+// Cualquier espacio (tabulador, salto de línea, `\r`...) separa palabras, no sólo ' '.
+// Si el texto es ASCII usa el recorrido por bytes, que es más rápido.
+pub fn first_word(s: &str) -> &str {
+    if s.is_ascii() {
+        first_word_ascii(s)
+    } else {
+        first_word_unicode(s)
+    }
+}
+
+// Devuelve `None` si no hay segunda palabra; los espacios repetidos entre palabras no cuentan
+pub fn second_word(s: &str) -> Option<&str> {
+    nth_word(s, 1)
+}
+
+// Cuenta palabras procesando el texto en bloques de 8 bytes: para cada bloque se calcula una
+// máscara con un bit por byte que es espacio y se cuentan los inicios de palabra con `count_ones`.
+// Sólo los espacios ASCII caben en un byte, así que un texto con otros caracteres usa el camino escalar.
+pub fn word_count_fast(s: &str) -> usize {
+    if !s.is_ascii() {
+        return s.split_whitespace().count();
+    }
+
+    let bytes = s.as_bytes();
+    let mut chunks = bytes.chunks_exact(8);
+    let mut count = 0;
+    // Si el byte anterior era espacio; al inicio del texto cuenta como tal
+    let mut previous_space = true;
+
+    for chunk in chunks.by_ref() {
+        let mask = whitespace_mask(u64::from_le_bytes(chunk.try_into().unwrap()));
+        let previous = (mask << 1) | previous_space as u8;
+        count += (!mask & previous).count_ones() as usize;
+        previous_space = mask & 0x80 != 0;
+    }
+
+    for &byte in chunks.remainder() {
+        let space = is_whitespace_byte(byte);
+        if !space && previous_space {
+            count += 1;
+        }
+        previous_space = space;
+    }
+    count
+}
+
+const LOW_BITS: u64 = 0x0101_0101_0101_0101;
+const HIGH_BITS: u64 = 0x8080_8080_8080_8080;
+
+// Un bit por byte (el bit i es el byte i) indicando si es espacio: `\t`, `\n`, `\x0B`, `\x0C`, `\r` o ` `.
+// Requiere bytes ASCII (< 0x80) para que las sumas no se acarreen entre bytes.
+fn whitespace_mask(x: u64) -> u8 {
+    // Bytes iguales a ' ': el XOR los deja en cero y detectamos los ceros
+    let space = zero_bytes(x ^ (LOW_BITS * b' ' as u64));
+    // Bytes en 0x09..=0x0D: >= 0x09 y no >= 0x0E
+    let at_least_tab = x + LOW_BITS * (0x80 - 0x09);
+    let at_least_after_cr = x + LOW_BITS * (0x80 - 0x0e);
+    let control = at_least_tab & !at_least_after_cr & HIGH_BITS;
+
+    // Junta el bit alto de cada byte en los 8 bits superiores
+    (((space | control) >> 7).wrapping_mul(0x0102_0408_1020_4080) >> 56) as u8
+}
+
+fn is_whitespace_byte(byte: u8) -> bool {
+    matches!(byte, b'\t'..=b'\r' | b' ')
+}
+
+// `n` empieza en cero; no se crea ningún `Vec`, el iterador avanza hasta la palabra pedida
+pub fn nth_word(s: &str, n: usize) -> Option<&str> {
+    s.split_whitespace().nth(n)
+}
+
+// Igual que `first_word`, pero mirando 8 bytes a la vez mientras el texto sea ASCII. Un bloque con
+// bytes no ASCII puede contener espacios Unicode (U+00A0, U+3000...), así que desde ahí se sigue por chars.
+pub fn first_word_fast(s: &str) -> &str {
+    let mut offset = 0;
+    for chunk in s.as_bytes().chunks_exact(8) {
+        let x = u64::from_le_bytes(chunk.try_into().unwrap());
+        if x & HIGH_BITS != 0 {
+            break;
+        }
+        let mask = whitespace_mask(x);
+        if mask != 0 {
+            return &s[..offset + mask.trailing_zeros() as usize];
+        }
+        offset += 8;
+    }
+
+    // Los bloques anteriores eran ASCII, así que `offset` es un límite de char
+    let (word, _) = scan_word(&s[offset..], char::is_whitespace);
+    &s[..offset + word.len()]
+}
+
+// Bit alto encendido en cada byte de `t` que vale cero, sin falsos positivos
+fn zero_bytes(t: u64) -> u64 {
+    !(((t & !HIGH_BITS) + !HIGH_BITS) | t | !HIGH_BITS)
+}
+
+// `\r` se trata como cualquier otro espacio, así que en líneas terminadas en `\r\n` no queda pegado a la palabra
+pub fn last_word(s: &str) -> Option<&str> {
+    s.split_whitespace().next_back()
+}
+
+// Divide el texto en `threads` trozos (en límites de char) y los cuenta en paralelo. Cuando un trozo
+// empieza en mitad de una palabra, esa palabra ya se contó en el trozo anterior y se descuenta.
+#[cfg(feature = "parallel")]
+pub fn word_count_parallel(s: &str, threads: usize) -> usize {
+    let threads = threads.max(1);
+    let chunk_len = s.len().div_ceil(threads).max(1);
+
+    let mut chunks = Vec::with_capacity(threads);
+    let mut rest = s;
+    while !rest.is_empty() {
+        let mut end = chunk_len.min(rest.len());
+        while !rest.is_char_boundary(end) {
+            end += 1;
+        }
+        let (chunk, tail) = rest.split_at(end);
+        chunks.push(chunk);
+        rest = tail;
+    }
+
+    let counts: usize = std::thread::scope(|scope| {
+        let handles: Vec<_> = chunks
+            .iter()
+            .map(|chunk| scope.spawn(move || chunk.split_whitespace().count()))
+            .collect();
+        handles.into_iter().map(|handle| handle.join().unwrap()).sum()
+    });
+
+    let split_words = chunks
+        .windows(2)
+        .filter(|pair| {
+            let ends_in_word = pair[0].chars().next_back().is_some_and(|c| !c.is_whitespace());
+            let starts_in_word = pair[1].chars().next().is_some_and(|c| !c.is_whitespace());
+            ends_in_word && starts_in_word
+        })
+        .count();
+
+    counts - split_words
+}
+
+pub fn word_count(s: &str) -> usize {
+    words(s).count()
+}
+
+// Separa la primera palabra del resto, cortando en el primer char que cumpla `is_delim`.
+// El resto empieza en el propio delimitador.
+fn scan_word(s: &str, is_delim: impl Fn(char) -> bool) -> (&str, &str) {
+    for (i, c) in s.char_indices() {
+        if is_delim(c) {
+            return s.split_at(i);
+        }
+    }
+    (s, "")
+}
+
+// Asigna a cada palabra distinta un id consecutivo; `resolve` hace el camino inverso
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct WordInterner {
+    ids: HashMap<String, u32>,
+    words: Vec<String>,
+}
+
+#[cfg(feature = "std")]
+impl WordInterner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn intern(&mut self, word: &str) -> u32 {
+        if let Some(&id) = self.ids.get(word) {
+            return id;
+        }
+        let id = self.words.len() as u32;
+        self.words.push(word.to_string());
+        self.ids.insert(word.to_string(), id);
+        id
+    }
+
+    pub fn resolve(&self, id: u32) -> Option<&str> {
+        self.words.get(id as usize).map(String::as_str)
+    }
+
+    pub fn intern_all(&mut self, s: &str) -> Vec<u32> {
+        s.split_whitespace().map(|word| self.intern(word)).collect()
+    }
+}
+
+// Recorre las palabras separadas por espacios sin reservar memoria: cada elemento es un slice de la entrada
+#[derive(Debug, Clone)]
+pub struct Words<'a> {
+    rest: &'a str,
+}
+
+pub fn words(s: &str) -> Words<'_> {
+    Words { rest: s }
+}
+
+impl<'a> Iterator for Words<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        self.rest = self.rest.trim_start();
+        if self.rest.is_empty() {
+            return None;
+        }
+        let (word, tail) = scan_word(self.rest, char::is_whitespace);
+        self.rest = tail;
+        Some(word)
+    }
+}
+
+// Lee la entrada por bloques y va entregando palabras propias, sin cargar todo el texto en memoria.
+// Una palabra puede quedar repartida entre dos lecturas, así que sus bytes se acumulan hasta el siguiente espacio.
+// Si una palabra no es UTF-8 válido se entregan antes las palabras anteriores, luego el error, y ahí termina:
+// el resto de la entrada no se lee.
+#[cfg(feature = "std")]
+pub fn words_from_reader<R: BufRead>(r: R) -> impl Iterator<Item = io::Result<String>> {
+    ReaderWords {
+        reader: r,
+        word: Vec::new(),
+        pending: VecDeque::new(),
+        error: None,
+        done: false,
+    }
+}
+
+#[cfg(feature = "std")]
+struct ReaderWords<R> {
+    reader: R,
+    word: Vec<u8>,
+    pending: VecDeque<String>,
+    error: Option<io::Error>,
+    done: bool,
+}
+
+#[cfg(feature = "std")]
+impl<R: BufRead> Iterator for ReaderWords<R> {
+    type Item = io::Result<String>;
+
+    fn next(&mut self) -> Option<io::Result<String>> {
+        loop {
+            if let Some(word) = self.pending.pop_front() {
+                return Some(Ok(word));
+            }
+            if let Some(e) = self.error.take() {
+                return Some(Err(e));
+            }
+            if self.done {
+                return None;
+            }
+
+            let buf = match self.reader.fill_buf() {
+                Ok(buf) => buf,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            };
+            if buf.is_empty() {
+                self.done = true;
+                if let Err(e) = finish_word(&mut self.word, &mut self.pending) {
+                    self.error = Some(e);
+                }
+                continue;
+            }
+
+            let mut used = buf.len();
+            for (i, &byte) in buf.iter().enumerate() {
+                if !is_whitespace_byte(byte) {
+                    self.word.push(byte);
+                } else if let Err(e) = finish_word(&mut self.word, &mut self.pending) {
+                    self.error = Some(e);
+                    self.done = true;
+                    used = i + 1;
+                    break;
+                }
+            }
+            self.reader.consume(used);
+        }
+    }
+}
+
+// Decodifica los bytes acumulados. Sólo se cortó en espacios ASCII, así que la palabra aún puede
+// contener espacios Unicode (como U+00A0) y se vuelve a separar con `words`.
+#[cfg(feature = "std")]
+fn finish_word(word: &mut Vec<u8>, pending: &mut VecDeque<String>) -> io::Result<()> {
+    if word.is_empty() {
+        return Ok(());
+    }
+    let bytes = std::mem::take(word);
+    let text = String::from_utf8(bytes).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    pending.extend(words(&text).map(String::from));
+    Ok(())
+}
+
+// Como `Words`, pero entrega el rango en bytes `(inicio, fin)` de cada palabra dentro del texto
+#[derive(Debug, Clone)]
+pub struct WordIndices<'a> {
+    source: &'a str,
+    words: Words<'a>,
+}
+
+pub fn word_indices(s: &str) -> WordIndices<'_> {
+    WordIndices {
+        source: s,
+        words: words(s),
+    }
+}
+
+impl Iterator for WordIndices<'_> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<(usize, usize)> {
+        let word = self.words.next()?;
+        // Cada palabra es un slice de `source`, así que la distancia entre punteros es su offset
+        let start = word.as_ptr() as usize - self.source.as_ptr() as usize;
+        Some((start, start + word.len()))
+    }
+}
+
+// Lista de palabras propias; se muestra separada por un espacio y se construye con `parse`
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WordList {
+    pub words: Vec<String>,
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for WordList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.words.join(" "))
+    }
+}
+
+#[cfg(feature = "std")]
+impl FromStr for WordList {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(WordList {
+            words: split_words_owned(s),
+        })
+    }
+}
+
+// A diferencia de `first_word`, que devuelve `""` si no encuentra nada, devuelve `None` para un
+// texto vacío o sólo con espacios
+pub fn first_word_opt(s: &str) -> Option<&str> {
+    words(s).next()
+}
+
+// Primera palabra cortando en el primer char que cumpla `is_delim`; recorre por chars, así que
+// los delimitadores multibyte funcionan igual que los ASCII
+pub fn first_word_by(s: &str, is_delim: impl Fn(char) -> bool) -> &str {
+    scan_word(s, is_delim).0
+}
+
+// Permite escribir `s.first_word()` en lugar de `first_word(&s)`
+pub trait WordExt {
+    fn first_word(&self) -> &str;
+    fn word_count(&self) -> usize;
+    fn nth_word(&self, n: usize) -> Option<&str>;
+}
+
+impl WordExt for str {
+    fn first_word(&self) -> &str {
+        first_word(self)
+    }
+
+    fn word_count(&self) -> usize {
+        word_count(self)
+    }
+
+    fn nth_word(&self, n: usize) -> Option<&str> {
+        nth_word(self, n)
+    }
+}
+
+// Una `String` por palabra: a diferencia de `words`, el resultado no depende de que la entrada siga viva
+#[cfg(feature = "std")]
+pub fn split_words_owned(s: &str) -> Vec<String> {
+    words(s).map(String::from).collect()
+}
+
+// La longitud se mide en chars para comparar igual las palabras con acentos; en empate gana la primera
+pub fn longest_word(s: &str) -> Option<&str> {
+    word_by_length(s, |length, best| length > best)
+}
+
+pub fn shortest_word(s: &str) -> Option<&str> {
+    word_by_length(s, |length, best| length < best)
+}
+
+fn word_by_length(s: &str, is_better: impl Fn(usize, usize) -> bool) -> Option<&str> {
+    let mut best: Option<(&str, usize)> = None;
+    for word in words(s) {
+        let length = word.chars().count();
+        if best.is_none_or(|(_, best_length)| is_better(length, best_length)) {
+            best = Some((word, length));
+        }
+    }
+    best.map(|(word, _)| word)
+}
+
+// Los espacios repetidos se reducen a uno en el resultado
+#[cfg(feature = "std")]
+pub fn reverse_words(s: &str) -> String {
+    words_rev(s).collect::<Vec<&str>>().join(" ")
+}
+
+// El resto ya viene sin espacios iniciales, así que se puede volver a pasar tal cual
+pub fn split_first_word(s: &str) -> Option<(&str, &str)> {
+    let s = s.trim_start();
+    if s.is_empty() {
+        return None;
+    }
+    let (word, rest) = scan_word(s, char::is_whitespace);
+    Some((word, rest.trim_start()))
+}
+
+// Resumen de un texto; `total_chars` suma los chars de las palabras, sin contar los espacios
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WordStats {
+    pub count: usize,
+    pub longest: Option<String>,
+    pub shortest: Option<String>,
+    pub total_chars: usize,
+}
+
+#[cfg(feature = "std")]
+pub fn word_stats(s: &str) -> WordStats {
+    WordStats {
+        count: word_count(s),
+        longest: longest_word(s).map(String::from),
+        shortest: shortest_word(s).map(String::from),
+        total_chars: words(s).map(|word| word.chars().count()).sum(),
+    }
+}
+
+// Offset en bytes de la primera palabra completa igual a `needle`, ignorando mayúsculas ASCII
+pub fn find_word_ci(haystack: &str, needle: &str) -> Option<usize> {
+    word_indices(haystack)
+        .find(|&(start, end)| haystack[start..end].eq_ignore_ascii_case(needle))
+        .map(|(start, _)| start)
+}
+
+// Como `Words`, pero empezando por la última palabra; útil para leer líneas de log de derecha a izquierda
+#[derive(Debug, Clone)]
+pub struct WordsRev<'a> {
+    rest: &'a str,
+}
+
+pub fn words_rev(s: &str) -> WordsRev<'_> {
+    WordsRev { rest: s }
+}
+
+impl<'a> Iterator for WordsRev<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        self.rest = self.rest.trim_end();
+        if self.rest.is_empty() {
+            return None;
+        }
+        let start = self
+            .rest
+            .char_indices()
+            .rev()
+            .find(|(_, c)| c.is_whitespace())
+            .map_or(0, |(i, c)| i + c.len_utf8());
+        let (rest, word) = self.rest.split_at(start);
+        self.rest = rest;
+        Some(word)
+    }
+}
+
+// El prefijo más largo de como máximo `max_bytes` que no corta ninguna palabra, y el resto sin espacios
+// iniciales. Si ni la primera palabra cabe, se devuelve entera igualmente para que un bucle que
+// llame repetidamente siempre avance.
+pub fn split_at_word_boundary(s: &str, max_bytes: usize) -> (&str, &str) {
+    let mut indices = word_indices(s);
+    let Some((_, first_end)) = indices.next() else {
+        return ("", "");
+    };
+    let end = indices
+        .take_while(|&(_, end)| end <= max_bytes)
+        .last()
+        .map_or(first_end, |(_, end)| end);
+    let (prefix, rest) = s.split_at(end);
+    (prefix, rest.trim_start())
+}
+
+// Una línea en blanco produce un slice vacío; acepta finales `\n` y `\r\n`
+pub fn first_word_per_line(s: &str) -> impl Iterator<Item = &str> {
+    s.lines().map(|line| first_word_opt(line).unwrap_or(""))
+}
+
+// A diferencia de `str::contains`, sólo acepta `word` como palabra completa
+pub fn contains_word(s: &str, word: &str) -> bool {
+    words(s).any(|item| item == word)
+}
+
+// `None` si el offset cae en un espacio, fuera del texto o en medio de un char, en lugar de entrar en pánico
+pub fn word_at_byte(s: &str, byte: usize) -> Option<&str> {
+    if !s.is_char_boundary(byte) {
+        return None;
+    }
+    word_indices(s)
+        .find(|&(start, end)| (start..end).contains(&byte))
+        .map(|(start, end)| &s[start..end])
+}
+
+// Toma posesión del String, como `takes_and_gives_back`, y devuelve la primera palabra y el resto
+// (sin espacios iniciales) como dos String propios. La primera palabra reutiliza la memoria de la entrada.
+#[cfg(feature = "std")]
+pub fn take_first_word(mut s: String) -> (String, String) {
+    let (start, end, rest) = match split_first_word(&s) {
+        Some((word, rest)) => {
+            let start = word.as_ptr() as usize - s.as_ptr() as usize;
+            (start, start + word.len(), rest.to_string())
+        }
+        None => return (String::new(), String::new()),
+    };
+    s.truncate(end);
+    s.drain(..start);
+    (s, rest)
+}
+
+// Analizador con cursor: además de pedir palabras, permite mirar el texto que queda sin consumir
+#[derive(Debug, Clone)]
+pub struct Tokenizer<'a> {
+    input: &'a str,
+    cursor: usize,
+}
+
+impl<'a> Tokenizer<'a> {
+    pub fn new(s: &'a str) -> Self {
+        Tokenizer { input: s, cursor: 0 }
+    }
+
+    pub fn next_word(&mut self) -> Option<&'a str> {
+        let rest = self.remaining();
+        let start = self.cursor + (rest.len() - rest.trim_start().len());
+        if start == self.input.len() {
+            self.cursor = start;
+            return None;
+        }
+        let (word, _) = scan_word(&self.input[start..], char::is_whitespace);
+        self.cursor = start + word.len();
+        Some(word)
+    }
+
+    // Lo que queda por leer, empezando justo después de la última palabra devuelta
+    pub fn remaining(&self) -> &'a str {
+        &self.input[self.cursor..]
+    }
+}
+
+pub fn count_matching_words(s: &str, pred: impl Fn(&str) -> bool) -> usize {
+    words(s).filter(|word| pred(word)).count()
+}
+
+// `to_uppercase` puede devolver varios chars (`ß` -> "SS"), por eso se extiende en lugar de empujar uno
+#[cfg(feature = "std")]
+pub fn capitalize_words(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    for word in words(s) {
+        if !result.is_empty() {
+            result.push(' ');
+        }
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            result.extend(first.to_uppercase());
+            result.extend(chars.flat_map(char::to_lowercase));
+        }
+    }
+    result
+}
+
+// Recorre bytes y sólo reconoce los espacios ASCII (`\t`, `\n`, `\x0B`, `\x0C`, `\r`, ` `). Pensada para
+// texto ASCII: con otros caracteres no falla, pero no corta en espacios Unicode como U+00A0.
+pub fn first_word_ascii(s: &str) -> &str {
+    let bytes = s.as_bytes();
+    for (i, &item) in bytes.iter().enumerate() {
+        if is_whitespace_byte(item) {
+            return &s[0..i];
+        }
+    }
+
+    s
+}
+
+// Recorre chars con `char_indices` y corta en cualquier espacio Unicode; válida para cualquier texto
+pub fn first_word_unicode(s: &str) -> &str {
+    scan_word(s, char::is_whitespace).0
+}
+
+// Guarda la primera palabra tras calcularla la primera vez, para bucles que la consultan muchas veces
+#[derive(Debug, Clone)]
+pub struct CachedWord<'a> {
+    source: &'a str,
+    first: OnceCell<&'a str>,
+    scan: fn(&str) -> &str,
+}
+
+impl<'a> CachedWord<'a> {
+    pub fn new(source: &'a str) -> Self {
+        CachedWord {
+            source,
+            first: OnceCell::new(),
+            scan: first_word,
+        }
+    }
+
+    // Permite a los tests contar cuántas veces se recorre el texto
+    #[cfg(all(test, feature = "std"))]
+    fn with_scan(source: &'a str, scan: fn(&str) -> &str) -> Self {
+        CachedWord {
+            source,
+            first: OnceCell::new(),
+            scan,
+        }
+    }
+
+    pub fn first_word(&self) -> &'a str {
+        self.first.get_or_init(|| (self.scan)(self.source))
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    // Texto pseudoaleatorio con espacios, tabuladores y saltos de línea entre letras
+    fn sample_text() -> String {
+        let mut seed: u32 = 7;
+        let mut sample = String::new();
+        for _ in 0..10_000 {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            sample.push(match (seed >> 16) % 8 {
+                0 => ' ',
+                1 => '\t',
+                2 => '\n',
+                _ => char::from(b'a' + (seed >> 8) as u8 % 26),
+            });
+        }
+        sample
+    }
+
+    #[test]
+    fn first_word_splits_on_any_whitespace() {
+        assert_eq!(first_word("hello\tworld"), "hello");
+        assert_eq!(first_word("hello\nworld"), "hello");
+        assert_eq!(first_word("hello\r\nworld"), "hello");
+    }
+
+    #[test]
+    fn first_word_ascii_and_unicode_paths_agree() {
+        for s in ["", "hello", "hello world", "\thello", "a\x0bb", "hello\r\nworld"] {
+            assert_eq!(first_word_ascii(s), first_word_unicode(s));
+            assert_eq!(first_word(s), first_word_ascii(s));
+        }
+        // El espacio duro U+00A0 sólo lo reconoce el camino Unicode
+        assert_eq!(first_word_unicode("hola\u{a0}mundo"), "hola");
+        assert_eq!(first_word("hola\u{a0}mundo"), "hola");
+    }
+
+    #[test]
+    fn first_word_fast_matches_first_word() {
+        let long_word = "a".repeat(1_000);
+        let inputs = [
+            "", "hello", "hello world", " hello", "año nuevo", "exactly8 bytes", "exactly8\tbytes",
+            "ocho1234\r\n", "largo\u{a0}sin ascii", "muy_largo_ñ\u{3000}x", long_word.as_str(),
+        ];
+        for s in inputs {
+            assert_eq!(first_word_fast(s), first_word(s));
+        }
+    }
+
+    #[test]
+    fn second_word_skips_extra_spaces() {
+        assert_eq!(second_word(""), None);
+        assert_eq!(second_word("hello"), None);
+        assert_eq!(second_word("hello world   "), Some("world"));
+        assert_eq!(second_word("hello   world"), Some("world"));
+    }
+
+    #[test]
+    fn nth_word_counts_from_zero() {
+        assert_eq!(nth_word("  uno dos tres", 0), Some("uno"));
+        assert_eq!(nth_word("  uno dos tres", 2), Some("tres"));
+        assert_eq!(nth_word("uno dos tres", 3), None);
+        assert_eq!(nth_word(" \t\n ", 0), None);
+    }
+
+    #[test]
+    fn last_word_ignores_trailing_whitespace() {
+        assert_eq!(last_word("foo bar  "), Some("bar"));
+        assert_eq!(last_word("foo bar\r\n"), Some("bar")); // `\r` cuenta como espacio
+        assert_eq!(last_word("   "), None);
+        assert_eq!(last_word(""), None);
+    }
+
+    #[test]
+    fn word_count_any_whitespace() {
+        assert_eq!(word_count(""), 0);
+        assert_eq!(word_count(" \t\n"), 0);
+        assert_eq!(word_count("uno\tdos"), 2);
+        assert_eq!(word_count("uno \t\n dos\r\ntres"), 3);
+        assert_eq!(word_count("  uno dos  "), 2);
+    }
+
+    #[test]
+    fn word_count_fast_matches_split_whitespace() {
+        let sample = sample_text();
+        assert_eq!(word_count_fast(&sample), sample.split_whitespace().count());
+        assert_eq!(word_count_fast(&sample), word_count(&sample));
+        assert_eq!(word_count_fast("  año  nuevo "), 2);
+    }
+
+    // Una palabra partida entre dos trozos no se cuenta dos veces
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn word_count_parallel_any_thread_count() {
+        let text = "uno dos tres cuatro cinco seis siete";
+        for threads in 1..=text.len() + 1 {
+            assert_eq!(word_count_parallel(text, threads), 7);
+        }
+        assert_eq!(word_count_parallel("año  año ", 4), 2);
+        let sample = sample_text();
+        assert_eq!(word_count_parallel(&sample, 3), word_count(&sample));
+    }
+
+    #[test]
+    fn word_interner_stable_ids() {
+        let mut interner = WordInterner::new();
+        let ids = interner.intern_all("el gato y el perro");
+        assert_eq!(ids, vec![0, 1, 2, 0, 3]);
+        assert_eq!(interner.intern("gato"), 1);
+        assert_eq!(interner.resolve(3), Some("perro"));
+        assert_eq!(interner.resolve(9), None);
+    }
+
+    #[test]
+    fn words_matches_split_whitespace() {
+        for s in ["", "   ", "uno", " uno  dos\ttres\n", "año\u{a0}nuevo\r\nfin", "\u{3000}x"] {
+            assert_eq!(words(s).collect::<Vec<_>>(), s.split_whitespace().collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn words_rev_is_words_reversed() {
+        for s in ["", "  ", "uno", "uno dos  tres  ", " año\tnuevo\r\n"] {
+            let mut forward: Vec<&str> = words(s).collect();
+            forward.reverse();
+            assert_eq!(words_rev(s).collect::<Vec<_>>(), forward);
+        }
+    }
+
+    #[test]
+    fn word_indices_are_byte_ranges() {
+        let accented = "café  déjà vu";
+        let ranges: Vec<(usize, usize)> = word_indices(accented).collect();
+        assert_eq!(ranges, vec![(0, 5), (7, 13), (14, 16)]);
+        let round_trip: Vec<&str> = ranges.iter().map(|&(start, end)| &accented[start..end]).collect();
+        assert_eq!(round_trip, vec!["café", "déjà", "vu"]);
+    }
+
+    // Con un buffer de 4 bytes "mundo" llega partida en dos lecturas
+    #[test]
+    fn words_from_reader_joins_split_words() {
+        let reader = io::BufReader::with_capacity(4, "hola  mundo\ncañón".as_bytes());
+        let read: Vec<String> = words_from_reader(reader).collect::<Result<_, _>>().unwrap();
+        assert_eq!(read, vec!["hola", "mundo", "cañón"]);
+    }
+
+    #[test]
+    fn word_list_parse_and_display() {
+        let list: WordList = "a  b\tc".parse().unwrap();
+        assert_eq!(list.words, vec!["a", "b", "c"]);
+        assert_eq!(list.to_string(), "a b c");
+        assert_eq!("a b c".parse::<WordList>().unwrap().to_string(), "a b c");
+    }
+
+    #[test]
+    fn first_word_opt_none_without_words() {
+        assert_eq!(first_word_opt(""), None);
+        assert_eq!(first_word_opt("   "), None);
+        assert_eq!(first_word_opt("  hi"), Some("hi"));
+    }
+
+    #[test]
+    fn first_word_by_custom_delimiters() {
+        assert_eq!(first_word_by("nombre,edad,ciudad", |c| c == ','), "nombre");
+        assert_eq!(first_word_by("uno\tdos", |c| c == '\t'), "uno");
+        assert_eq!(first_word_by("¿qué?¡sí!", |c| c == '?' || c == '¡'), "¿qué");
+        assert_eq!(first_word_by("sin delimitador", |c| c == ';'), "sin delimitador");
+    }
+
+    #[test]
+    fn word_ext_methods_on_string() {
+        let owned = String::from("hola mundo cruel");
+        assert_eq!(owned.first_word(), "hola");
+        assert_eq!(owned.word_count(), 3);
+        assert_eq!(owned.nth_word(2), Some("cruel"));
+        assert_eq!(owned.nth_word(3), None);
+    }
+
+    #[test]
+    fn split_words_owned_outlives_source() {
+        let mut source = String::from("uno dos  tres");
+        let owned_words = split_words_owned(&source);
+        source.clear();
+        assert_eq!(owned_words, vec!["uno", "dos", "tres"]);
+    }
+
+    #[test]
+    fn longest_and_shortest_count_chars() {
+        assert_eq!(longest_word("uno tres dos cinco"), Some("cinco"));
+        assert_eq!(longest_word("ab cd"), Some("ab")); // empate: la primera
+        assert_eq!(shortest_word("ab cd efg"), Some("ab"));
+        assert_eq!(longest_word("única"), Some("única"));
+        assert_eq!(shortest_word("   "), None);
+        // "ñañá" ocupa 6 bytes pero sólo 4 chars, así que "hello" es más larga
+        assert_eq!(longest_word("ñañá hello"), Some("hello"));
+        assert_eq!(shortest_word("hello ñañá"), Some("ñañá"));
+    }
+
+    #[test]
+    fn reverse_words_order() {
+        assert_eq!(reverse_words("the quick brown fox"), "fox brown quick the");
+        assert_eq!(reverse_words("  uno   dos "), "dos uno");
+        assert_eq!(reverse_words("solo"), "solo");
+        assert_eq!(reverse_words(""), "");
+    }
+
+    #[test]
+    fn split_first_word_until_empty() {
+        let mut rest = "  el   perro ladra ";
+        let mut tokens = Vec::new();
+        while let Some((word, tail)) = split_first_word(rest) {
+            tokens.push(word);
+            rest = tail;
+        }
+        assert_eq!(tokens, vec!["el", "perro", "ladra"]);
+        assert_eq!(split_first_word("uno  dos"), Some(("uno", "dos")));
+        assert_eq!(split_first_word(" \t"), None);
+    }
+
+    #[test]
+    fn word_stats_summary() {
+        assert_eq!(
+            word_stats("el murciélago come"),
+            WordStats {
+                count: 3,
+                longest: Some(String::from("murciélago")),
+                shortest: Some(String::from("el")),
+                total_chars: 16,
+            }
+        );
+        assert_eq!(word_stats(""), WordStats::default());
+    }
+
+    #[test]
+    fn find_word_ci_whole_words() {
+        let log = "warn: disk almost full; Error: disk full";
+        assert_eq!(find_word_ci(log, "error:"), Some(24));
+        assert_eq!(find_word_ci(log, "WARN:"), Some(0));
+        assert_eq!(find_word_ci("errors everywhere", "error"), None); // no vale una parte de la palabra
+    }
+
+    #[test]
+    fn split_at_word_boundary_within_bytes() {
+        assert_eq!(split_at_word_boundary("hola mundo", 10), ("hola mundo", ""));
+        assert_eq!(split_at_word_boundary("hola mundo cruel", 12), ("hola mundo", "cruel"));
+        assert_eq!(split_at_word_boundary("supercalifragilistico x", 5), ("supercalifragilistico", "x"));
+        assert_eq!(split_at_word_boundary("hola mundo", 0), ("hola", "mundo"));
+    }
+
+    #[test]
+    fn first_word_per_line_blank_and_crlf() {
+        let text = "hola mundo\r\n\n   \n  adiós amigos\nfin";
+        let firsts: Vec<&str> = first_word_per_line(text).collect();
+        assert_eq!(firsts, vec!["hola", "", "", "adiós", "fin"]);
+    }
+
+    #[test]
+    fn contains_word_not_substring() {
+        assert!("category cats".contains("cat"));
+        assert!(!contains_word("category cats", "cat"));
+        assert!(contains_word("the cat sat", "cat"));
+        assert!(contains_word("--verbose --dry-run", "--dry-run"));
+    }
+
+    #[test]
+    fn word_at_byte_under_cursor() {
+        let line = "el camión azul";
+        assert_eq!(word_at_byte(line, 4), Some("camión"));
+        assert_eq!(word_at_byte(line, 2), None); // espacio
+        assert_eq!(word_at_byte(line, 99), None); // fuera del texto
+        assert_eq!(word_at_byte(line, 8), None); // dentro de `ó`
+    }
+
+    #[test]
+    fn take_first_word_owned_parts() {
+        let (mut command, args) = take_first_word(String::from("  git   commit -m msg"));
+        assert_eq!(command, "git");
+        assert_eq!(args, "commit -m msg");
+        command.push_str("-lfs"); // cada parte tiene su propio dueño
+        assert_eq!((command.as_str(), args.as_str()), ("git-lfs", "commit -m msg"));
+    }
+
+    #[test]
+    fn tokenizer_words_and_remaining() {
+        let mut tokenizer = Tokenizer::new("GET /index.html  HTTP/1.1 extra");
+        assert_eq!(tokenizer.next_word(), Some("GET"));
+        assert_eq!(tokenizer.remaining(), " /index.html  HTTP/1.1 extra");
+        assert_eq!(tokenizer.next_word(), Some("/index.html"));
+        assert_eq!(tokenizer.remaining().trim_start().split_once(' '), Some(("HTTP/1.1", "extra")));
+        assert_eq!(tokenizer.next_word(), Some("HTTP/1.1"));
+        assert_eq!(tokenizer.next_word(), Some("extra"));
+        assert_eq!(tokenizer.next_word(), None);
+        assert_eq!(tokenizer.remaining(), "");
+    }
+
+    #[test]
+    fn count_matching_words_predicate() {
+        let data = "id 42 tiene 7 elementos y 1000 bytes";
+        assert_eq!(count_matching_words(data, |word| word.chars().all(|c| c.is_ascii_digit())), 3);
+        assert_eq!(count_matching_words(data, |word| word.chars().count() > 4), 3);
+    }
+
+    #[test]
+    fn capitalize_words_unicode() {
+        assert_eq!(capitalize_words("hello WORLD"), "Hello World");
+        assert_eq!(capitalize_words(""), "");
+        assert_eq!(capitalize_words("  éxito   ñandú"), "Éxito Ñandú");
+        assert_eq!(capitalize_words("ßtraße"), "SStraße"); // `ß` en mayúscula son dos chars
+    }
+
+    #[test]
+    fn words_from_reader_stops_at_invalid_utf8() {
+        let input: &[u8] = b"ok \xff bad more";
+        let got: Vec<_> = words_from_reader(input).collect();
+        assert_eq!(got.len(), 2);
+        assert_eq!(got[0].as_ref().unwrap(), "ok");
+        assert_eq!(got[1].as_ref().unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+        // Igual cuando la palabra inválida queda repartida entre lecturas pequeñas
+        let reader = io::BufReader::with_capacity(2, b"uno dos \xc3 tres".as_slice());
+        let got: Vec<_> = words_from_reader(reader).map(|w| w.map_err(|e| e.kind())).collect();
+        assert_eq!(
+            got,
+            [Ok("uno".to_string()), Ok("dos".to_string()), Err(io::ErrorKind::InvalidData)]
+        );
+    }
+
+    #[test]
+    fn cached_word_computes_once() {
+        std::thread_local! {
+            static SCANS: core::cell::Cell<usize> = const { core::cell::Cell::new(0) };
+        }
+        fn counting_first_word(s: &str) -> &str {
+            SCANS.with(|scans| scans.set(scans.get() + 1));
+            first_word(s)
+        }
+
+        let cached = CachedWord::with_scan("hola mundo", counting_first_word);
+        assert_eq!(SCANS.with(|scans| scans.get()), 0); // construirlo no recorre el texto
+        for _ in 0..3 {
+            assert_eq!(cached.first_word(), "hola");
+        }
+        assert_eq!(SCANS.with(|scans| scans.get()), 1);
+
+        // Un clon ya calculado comparte el valor guardado
+        let copy = cached.clone();
+        assert_eq!(copy.first_word(), "hola");
+        assert_eq!(SCANS.with(|scans| scans.get()), 1);
+    }
+}
@@ -0,0 +1,20 @@
+// Comprueba que las funciones de slices se pueden usar desde un crate `#![no_std]`:
+// `cargo test --no-default-features --test no_std`
+#![no_std]
+
+use rust_teo::slice::{first_n, safe_slice, try_slice, SliceError};
+use rust_teo::words::{first_word, last_word, nth_word, word_count, words, WordExt};
+
+#[test]
+fn core_helpers_work_without_std() {
+    assert_eq!(first_word("hola mundo"), "hola");
+    assert_eq!(nth_word("uno dos tres", 2), Some("tres"));
+    assert_eq!(last_word("uno dos "), Some("dos"));
+    assert_eq!(word_count(" uno\tdos "), 2);
+    assert!(words("a b c").eq(["a", "b", "c"]));
+    assert_eq!("a b".word_count(), 2);
+
+    assert_eq!(safe_slice("héllo", 0, 2), None);
+    assert_eq!(try_slice("héllo", 0, 2), Err(SliceError::NotCharBoundary(2)));
+    assert_eq!(first_n(&[1, 2, 3], 5), &[1, 2, 3]);
+}
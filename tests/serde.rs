@@ -0,0 +1,35 @@
+// Ida y vuelta por JSON de los tipos que derivan serde: `cargo test --features serde --test serde`
+#![cfg(all(feature = "serde", feature = "std"))]
+
+use rust_teo::text::Case;
+use rust_teo::words::{word_stats, WordList, WordStats};
+
+#[test]
+fn word_list_round_trips_through_json() {
+    let list: WordList = "hola  mundo cañón".parse().unwrap();
+    let json = serde_json::to_string(&list).unwrap();
+    assert_eq!(json, r#"{"words":["hola","mundo","cañón"]}"#);
+    assert_eq!(serde_json::from_str::<WordList>(&json).unwrap(), list);
+}
+
+#[test]
+fn case_round_trips_through_json() {
+    for case in [Case::Snake, Case::Kebab, Case::Camel, Case::Pascal, Case::Upper, Case::Lower] {
+        let json = serde_json::to_string(&case).unwrap();
+        assert_eq!(serde_json::from_str::<Case>(&json).unwrap(), case);
+    }
+    assert_eq!(serde_json::to_string(&Case::Kebab).unwrap(), r#""Kebab""#);
+}
+
+#[test]
+fn word_stats_round_trips_through_json() {
+    let stats = word_stats("el murciélago  vuela");
+    let json = serde_json::to_string(&stats).unwrap();
+    assert_eq!(serde_json::from_str::<WordStats>(&json).unwrap(), stats);
+
+    // Un texto vacío deja `longest` y `shortest` en `null`
+    let empty = word_stats("   ");
+    let json = serde_json::to_string(&empty).unwrap();
+    assert_eq!(json, r#"{"count":0,"longest":null,"shortest":null,"total_chars":0}"#);
+    assert_eq!(serde_json::from_str::<WordStats>(&json).unwrap(), empty);
+}